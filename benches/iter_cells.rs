@@ -1,6 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use altar::buffer::Buffer;
 
+#[allow(clippy::explicit_counter_loop)]
 pub fn benchmark_iter_cells(c: &mut Criterion) {
     let buffer = Buffer::new(100, 100);
 
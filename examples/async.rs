@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use altar::*;
 use tokio::sync::mpsc;
 
@@ -6,26 +8,18 @@ struct SimpleAsyncApp {
     super_charge: Option<usize>,
 }
 
-enum Message {
-    Charge,
-}
-
 impl SimpleAsyncApp {
-    fn handle_key_event(
-        &mut self,
-        key_event: KeyEvent,
-        tx: &mpsc::UnboundedSender<Message>,
-    ) -> bool {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Command<()> {
         match key_event.code {
             KeyCode::Down => self.count = self.count.saturating_sub(1),
             KeyCode::Up => self.count = self.count.saturating_add(1),
             KeyCode::Left => self.charged_adjust(false),
             KeyCode::Right => self.charged_adjust(true),
-            KeyCode::Char('q') => return false,
-            KeyCode::Char('c') => self.start_charging(tx),
+            KeyCode::Char('q') => return Command::exit(()),
+            KeyCode::Char('c') => self.start_charging(),
             _ => {}
         }
-        true
+        Command::none()
     }
 
     fn charged_adjust(&mut self, should_add: bool) {
@@ -41,22 +35,16 @@ impl SimpleAsyncApp {
         }
     }
 
-    fn start_charging(&mut self, tx: &mpsc::UnboundedSender<Message>) {
+    fn start_charging(&mut self) {
         if self.super_charge.is_none() {
             self.super_charge = Some(0);
-            let tx = tx.clone();
-            tokio::spawn(async move {
-                for _ in 0..10 {
-                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-                    tx.send(Message::Charge).unwrap();
-                }
-            });
         }
     }
 }
 
 impl AsyncTerminalApp for SimpleAsyncApp {
-    type Message = Message;
+    type Message = ();
+    type Output = ();
 
     fn render(&self) -> impl View {
         let count = self.count.to_string();
@@ -79,14 +67,23 @@ impl AsyncTerminalApp for SimpleAsyncApp {
     fn update(
         &mut self,
         event: Event<Self::Message>,
-        tx: &mpsc::UnboundedSender<Self::Message>,
-    ) -> bool {
+        _tx: &mpsc::UnboundedSender<Self::Message>,
+    ) -> Command<()> {
         match event {
-            Event::Key(key_event) => self.handle_key_event(key_event, tx),
-            Event::Message(Message::Charge) => {
+            Event::Key(key_event) => self.handle_key_event(key_event),
+            Event::Tick(_) => {
                 self.super_charge = self.super_charge.map(|x| x.saturating_add(1));
-                true
+                Command::none()
             }
+            _ => Command::none(),
+        }
+    }
+
+    // Charge up every 50ms until full
+    fn subscriptions(&self) -> Vec<Subscription> {
+        match self.super_charge {
+            Some(charge) if charge < 10 => vec![Subscription::every(Duration::from_millis(50))],
+            _ => Vec::new(),
         }
     }
 }
@@ -98,5 +95,5 @@ async fn main() {
         super_charge: None,
     };
 
-    app.run(false).await;
+    let _ = app.run(RunOptions::new()).await;
 }
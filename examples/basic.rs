@@ -10,7 +10,7 @@ impl SyncTerminalApp for SimpleApp {
         let count = self.count.to_string();
 
         // for the numbers 0 through count, have a Vec of text(<index>)
-        let children = for_each_view(0..self.count, |i| text(&i.to_string()));
+        let children = for_each_view(0..self.count, |i| text(i.to_string()));
         vstack((hstack((text("Count:"), text(&count))), vstack(children)))
     }
 
@@ -25,5 +25,7 @@ impl SyncTerminalApp for SimpleApp {
 
 fn main() {
     let mut app = SimpleApp { count: 5 };
-    app.run(false);
+    if let Err(error) = app.run(RunOptions::new()) {
+        eprintln!("{error}");
+    }
 }
@@ -1,6 +1,7 @@
 use altar::*;
 use tokio::sync::mpsc;
 
+#[derive(Default)]
 struct BinaryTutorialApp {
     count: i16,
     shift: i16,
@@ -9,18 +10,6 @@ struct BinaryTutorialApp {
     show_two_complement: bool,
 }
 
-impl Default for BinaryTutorialApp {
-    fn default() -> Self {
-        Self {
-            count: 0,
-            shift: 0,
-            show_alphabet: false,
-            show_colors: false,
-            show_two_complement: false,
-        }
-    }
-}
-
 enum Message {}
 
 impl BinaryTutorialApp {
@@ -28,7 +17,7 @@ impl BinaryTutorialApp {
         &mut self,
         key_event: KeyEvent,
         _tx: &mpsc::UnboundedSender<Message>,
-    ) -> bool {
+    ) -> Command<Message> {
         match key_event.code {
             KeyCode::Down => {
                 if self.count == 0 {
@@ -46,11 +35,11 @@ impl BinaryTutorialApp {
             KeyCode::Right => {
                 self.shift = self.shift.saturating_add(1);
             }
-            KeyCode::Char('q') => return false,
+            KeyCode::Char('q') => return Command::exit(()),
             KeyCode::Char('a') => self.show_alphabet = !self.show_alphabet,
             KeyCode::Char('c') => self.show_colors = !self.show_colors,
             KeyCode::Char('t') => self.show_two_complement = !self.show_two_complement,
-            KeyCode::Char(digit) if digit.is_digit(10) => {
+            KeyCode::Char(digit) if digit.is_ascii_digit() => {
                 let digit = digit.to_digit(10).unwrap();
                 self.count = digit as i16;
                 self.shift = 0;
@@ -58,8 +47,8 @@ impl BinaryTutorialApp {
             _ => {}
         }
         // wrap count around if greater than 16
-        self.count = self.count % 16;
-        true
+        self.count %= 16;
+        Command::none()
     }
 }
 
@@ -170,6 +159,7 @@ fn render_helper(parts: Vec<String>) -> impl View {
 
 impl AsyncTerminalApp for BinaryTutorialApp {
     type Message = Message;
+    type Output = ();
 
     fn render(&self) -> impl View {
         vstack((
@@ -209,10 +199,10 @@ impl AsyncTerminalApp for BinaryTutorialApp {
         &mut self,
         event: Event<Self::Message>,
         tx: &mpsc::UnboundedSender<Self::Message>,
-    ) -> bool {
+    ) -> Command<Message> {
         match event {
             Event::Key(key_event) => self.handle_key_event(key_event, tx),
-            Event::Message(_) => true,
+            _ => Command::none(),
         }
     }
 }
@@ -220,5 +210,5 @@ impl AsyncTerminalApp for BinaryTutorialApp {
 #[tokio::main]
 async fn main() {
     let mut app = BinaryTutorialApp::default();
-    app.run(true).await;
+    let _ = app.run(RunOptions::new().full_screen(true)).await;
 }
@@ -0,0 +1,78 @@
+//! Picks a line from stdin and prints it to stdout, drawing the list on the terminal.
+//!
+//! ```sh
+//! ls | cargo run --example picker | xargs echo "You picked"
+//! ```
+use std::io::BufRead;
+
+use altar::*;
+use tokio::sync::mpsc;
+
+struct Picker {
+    items: Vec<String>,
+    selected: usize,
+}
+
+impl AsyncTerminalApp for Picker {
+    type Message = ();
+    type Output = String;
+
+    fn render(&self) -> impl View {
+        let rows = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let is_selected = index == self.selected;
+                hstack((if is_selected { ">" } else { " " }, item.clone()))
+                    .bold_when(is_selected)
+                    .id(index)
+            })
+            .collect::<Vec<_>>();
+        scroll_view(rows).scroll_to(self.selected).frame(
+            None,
+            None,
+            None,
+            Some(10),
+            Alignment::TOP_LEFT,
+        )
+    }
+
+    fn update(
+        &mut self,
+        event: Event<Self::Message>,
+        _sender: &mpsc::UnboundedSender<Self::Message>,
+    ) -> Command<(), String> {
+        if let Event::Key(key) = event {
+            match key.code {
+                KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down => self.selected = (self.selected + 1).min(self.items.len() - 1),
+                KeyCode::Enter => return Command::exit(self.items[self.selected].clone()),
+                _ => {}
+            }
+        }
+        Command::none()
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let items = std::io::stdin()
+        .lock()
+        .lines()
+        .map_while(Result::ok)
+        .collect::<Vec<_>>();
+    if items.is_empty() {
+        return;
+    }
+
+    let mut picker = Picker { items, selected: 0 };
+    match picker.run(RunOptions::new().output(Output::Tty)).await {
+        Ok(item) => println!("{item}"),
+        Err(Error::Interrupted) => std::process::exit(130),
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+    }
+}
@@ -32,14 +32,18 @@ enum AppMode {
     Adding,
 }
 
-enum Message {}
+enum Message {
+    InputChanged(String),
+    Submit,
+    Toggle(usize),
+}
 
 impl TodoApp {
     fn handle_key_event(
         &mut self,
         key_event: KeyEvent,
         tx: &mpsc::UnboundedSender<Message>,
-    ) -> bool {
+    ) -> Command<Message> {
         match self.mode {
             AppMode::Viewing => self.handle_viewing_mode(key_event, tx),
             AppMode::Adding => self.handle_adding_mode(key_event),
@@ -50,51 +54,49 @@ impl TodoApp {
         &mut self,
         key_event: KeyEvent,
         _tx: &mpsc::UnboundedSender<Message>,
-    ) -> bool {
+    ) -> Command<Message> {
         match key_event.code {
-            KeyCode::Char('q') => return false,
+            KeyCode::Char('q') => return Command::exit(()),
             KeyCode::Char('n') => self.mode = AppMode::Adding,
-            KeyCode::Up => {
-                if self.todo_index > 0 {
-                    self.todo_index -= 1;
-                }
+            KeyCode::Up if self.todo_index > 0 => {
+                self.todo_index -= 1;
             }
-            KeyCode::Down => {
-                if self.todo_index < self.todos.len().saturating_sub(1) {
-                    self.todo_index += 1;
-                }
+            KeyCode::Down if self.todo_index < self.todos.len().saturating_sub(1) => {
+                self.todo_index += 1;
             }
-            KeyCode::Char(' ') => {
-                if !self.todos.is_empty() {
-                    let todo = &mut self.todos[self.todo_index];
-                    todo.is_complete = !todo.is_complete;
-                }
+            KeyCode::Char(' ') if !self.todos.is_empty() => {
+                let todo = &mut self.todos[self.todo_index];
+                todo.is_complete = !todo.is_complete;
             }
             _ => {}
         }
-        true
+        Command::none()
     }
 
-    fn handle_adding_mode(&mut self, key_event: KeyEvent) -> bool {
-        match key_event.code {
-            KeyCode::Char(c) => self.input.push(c),
-            KeyCode::Backspace => {
-                self.input.pop();
-            }
-            KeyCode::Enter => {
-                if !self.input.is_empty() {
-                    self.todos.insert(0, Todo::new(&self.input));
-                    self.input.clear();
-                    self.mode = AppMode::Viewing;
-                }
-            }
-            KeyCode::Esc => {
+    fn handle_adding_mode(&mut self, key_event: KeyEvent) -> Command<Message> {
+        if key_event.code == KeyCode::Esc {
+            self.input.clear();
+            self.mode = AppMode::Viewing;
+        }
+        Command::none()
+    }
+
+    fn handle_message(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::InputChanged(input) => self.input = input,
+            Message::Submit if !self.input.is_empty() => {
+                self.todos.insert(0, Todo::new(&self.input));
                 self.input.clear();
                 self.mode = AppMode::Viewing;
             }
-            _ => {}
+            Message::Submit => {}
+            Message::Toggle(index) => {
+                let todo = &mut self.todos[index];
+                todo.is_complete = !todo.is_complete;
+                self.todo_index = index;
+            }
         }
-        true
+        Command::none()
     }
 
     fn render_todo(&self, index: usize, todo: &Todo) -> impl View {
@@ -112,12 +114,15 @@ impl TodoApp {
         ))
         .dim_when(todo.is_complete)
         .bold_when(is_selected)
+        .on_click(move || Message::Toggle(index))
     }
 
     fn input_view(&self) -> impl View {
         hstack((
             text("+"), //
-            text(self.input.clone()),
+            text_field(&self.input, Message::InputChanged)
+                .placeholder("What needs doing?")
+                .on_submit(|_| Message::Submit),
         ))
         .green()
         .bold()
@@ -140,6 +145,7 @@ impl TodoApp {
 
 impl AsyncTerminalApp for TodoApp {
     type Message = Message;
+    type Output = ();
 
     fn render(&self) -> impl View {
         let todos = self.render_todos();
@@ -160,23 +166,20 @@ impl AsyncTerminalApp for TodoApp {
         ))
         .spacing(2);
 
-        vstack((
-            altar::view::RenderCounter {},
-            todos.fill(),
-            commands_view,
-        ))
-        .border()
-        .title(" TODOS ")
+        vstack((altar::view::RenderCounter {}, todos.fill(), commands_view))
+            .border()
+            .title(" TODOS ")
     }
 
     fn update(
         &mut self,
         event: Event<Self::Message>,
         tx: &mpsc::UnboundedSender<Self::Message>,
-    ) -> bool {
+    ) -> Command<Message> {
         match event {
             Event::Key(key_event) => self.handle_key_event(key_event, tx),
-            Event::Message(_) => true,
+            Event::Message(message) => self.handle_message(message),
+            _ => Command::none(),
         }
     }
 
@@ -194,5 +197,7 @@ async fn main() {
         todo_index: 0,
     };
 
-    app.run(true).await;
+    let _ = app
+        .run(RunOptions::new().full_screen(true).mouse_capture(true))
+        .await;
 }
@@ -1,11 +1,11 @@
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 
 use altar::*;
 
 #[tokio::main]
 async fn main() {
     let mut app = TourApp::default();
-    app.run(true).await;
+    let _ = app.run(RunOptions::new().full_screen(true)).await;
 }
 
 #[derive(Clone, Copy, PartialEq, Hash)]
@@ -85,12 +85,9 @@ impl Default for TourApp {
     }
 }
 
-enum Message {
-    Tick,
-}
-
 impl AsyncTerminalApp for TourApp {
-    type Message = Message;
+    type Message = ();
+    type Output = ();
 
     fn render(&self) -> impl View {
         let main_view = match self.tab {
@@ -106,11 +103,11 @@ impl AsyncTerminalApp for TourApp {
         &mut self,
         event: Event<Self::Message>,
         _sender: &tokio::sync::mpsc::UnboundedSender<Self::Message>,
-    ) -> bool {
+    ) -> Command<()> {
         match event {
             Event::Key(key_event) => {
                 if key_event.code == KeyCode::Char('q') {
-                    return false;
+                    return Command::exit(());
                 }
 
                 if key_event.code == KeyCode::Tab {
@@ -125,22 +122,20 @@ impl AsyncTerminalApp for TourApp {
                     Tab::ZStack => handle_key_zstack_tab(&mut self.zstack_tab, key_event),
                 }
             }
-            Event::Message(Message::Tick) => {
+            Event::Tick(_) => {
                 self.tick = self.tick.wrapping_add(1);
             }
+            _ => {}
         }
-        true
+        Command::none()
     }
 
-    fn init(&mut self, sender: &tokio::sync::mpsc::UnboundedSender<Self::Message>) {
-        let sender_clone = sender.clone();
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(17));
-            loop {
-                interval.tick().await;
-                sender_clone.send(Message::Tick).unwrap();
-            }
-        });
+    // Only the ZStack tab is animated
+    fn subscriptions(&self) -> Vec<Subscription> {
+        match self.tab {
+            Tab::ZStack => vec![Subscription::every(Duration::from_millis(17))],
+            _ => Vec::new(),
+        }
     }
 }
 
@@ -170,7 +165,7 @@ fn tab_bar_view(tab: Tab) -> impl View {
         .title(" TABS ")
 }
 
-/// FRAME TAB
+// FRAME TAB
 
 fn handle_key_frame_tab(tab: &mut FrameTab, event: KeyEvent) {
     tab.alignment = modify_alignment_with_key(tab.alignment, event.code);
@@ -194,7 +189,7 @@ fn frame_tab_view(frame_tab: &FrameTab) -> impl View {
     .title(" FRAME ")
 }
 
-/// LIST TAB
+// LIST TAB
 
 pub struct ListTab {
     selected_index: usize,
@@ -251,7 +246,9 @@ pub fn list_tab_view(list_tab: &ListTab) -> impl View {
     .fill_horizontally();
 
     hstack((
-        scroll_view(items, list_tab.selected_index)
+        scroll_view(items)
+            .scroll_to(list_tab.selected_index)
+            .scrollbar(true)
             .fill_vertically()
             .border()
             .border_style(BorderStyle::Rounded)
@@ -263,44 +260,7 @@ pub fn list_tab_view(list_tab: &ListTab) -> impl View {
     ))
 }
 
-// TODO: Improve this and pull it into the library.
-pub fn scroll_view<V: View + Clone>(
-    views: Vec<IdentifiedView<V>>,
-    selected_index: usize,
-) -> impl View {
-    with_size(move |available_size| {
-        let sizes = views
-            .iter()
-            .map(|view| view.value.size(available_size))
-            .collect::<Vec<_>>();
-
-        let mut start_offset = 0;
-        let mut end_offset = 0;
-
-        let mut includes_selected = false;
-
-        while !includes_selected {
-            let mut total_height = 0;
-            for (i, size) in sizes.iter().enumerate().skip(start_offset) {
-                total_height += size.height;
-                if total_height > available_size.height {
-                    break;
-                }
-                end_offset = i;
-            }
-
-            if (start_offset..=end_offset).contains(&selected_index) {
-                includes_selected = true;
-            } else {
-                start_offset += 1;
-            }
-        }
-
-        vstack(views[start_offset..=end_offset].to_vec())
-    })
-}
-
-/// ZSTACK TAB
+// ZSTACK TAB
 
 fn handle_key_zstack_tab(tab: &mut ZStackTab, event: KeyEvent) {
     tab.alignment = modify_alignment_with_key(tab.alignment, event.code);
@@ -331,7 +291,7 @@ fn stack_tab_view(tick: usize, tab: &ZStackTab) -> impl View {
     let background = with_size(move |size| {
         fn make_line(line_number: u16, width: u16, tick: usize) -> impl View {
             let base_string = format!("{} ", (line_number as usize) + tick);
-            let repeat_count = (width as usize + base_string.len() - 1) / base_string.len();
+            let repeat_count = (width as usize).div_ceil(base_string.len());
             let repeated_string: String = base_string.repeat(repeat_count);
             repeated_string[..width as usize].to_string()
         }
@@ -4,6 +4,9 @@ use crate::Color;
 
 use super::Modifier;
 
+/// The symbol of a cell covered by the right half of a wide character.
+pub(crate) const CONTINUATION: &str = "";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cell {
     pub(crate) symbol: CompactString,
@@ -33,6 +36,28 @@ impl Cell {
         self
     }
 
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn fg(&self) -> Color {
+        self.fg
+    }
+
+    pub fn bg(&self) -> Color {
+        self.bg
+    }
+
+    pub fn modifier(&self) -> Modifier {
+        self.modifier
+    }
+
+    /// Returns true if the cell is covered by the wide character to its left,
+    /// and so is not printed itself.
+    pub fn is_continuation(&self) -> bool {
+        self.symbol == CONTINUATION
+    }
+
     pub fn new(symbol: &str) -> Self {
         Self {
             symbol: symbol.into(),
@@ -49,6 +74,7 @@ impl Cell {
         self.modifier = Modifier::empty();
     }
 
+    #[allow(clippy::inherent_to_string)]
     pub(crate) fn to_string(&self) -> String {
         use crossterm::style::{
             Attribute, Color as CrosstermColor, Print, SetAttribute, SetBackgroundColor,
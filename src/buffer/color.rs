@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::io::{self, Write};
 
 use bitflags::bitflags;
 use crossterm::queue;
@@ -19,76 +19,81 @@ bitflags! {
 }
 
 impl Modifier {
-    pub fn write_diff<W: Write>(next: Modifier, previous: Modifier, writer: &mut W) {
+    pub fn write_diff<W: Write>(
+        next: Modifier,
+        previous: Modifier,
+        writer: &mut W,
+    ) -> io::Result<()> {
         use crossterm::style::*;
 
         let removed = previous.difference(next);
         let added = next.difference(previous);
 
         if removed.contains(Modifier::BOLD) || removed.contains(Modifier::DIM) {
-            queue!(writer, SetAttribute(Attribute::NormalIntensity)).unwrap();
+            queue!(writer, SetAttribute(Attribute::NormalIntensity))?;
             if next.contains(Modifier::DIM) {
-                queue!(writer, SetAttribute(Attribute::Dim)).unwrap();
+                queue!(writer, SetAttribute(Attribute::Dim))?;
             } else if next.contains(Modifier::BOLD) {
-                queue!(writer, SetAttribute(Attribute::Bold)).unwrap();
+                queue!(writer, SetAttribute(Attribute::Bold))?;
             }
         }
 
         if removed.contains(Modifier::ITALIC) {
-            queue!(writer, SetAttribute(Attribute::NoItalic)).unwrap();
+            queue!(writer, SetAttribute(Attribute::NoItalic))?;
         }
 
         if removed.contains(Modifier::UNDERLINE) {
-            queue!(writer, SetAttribute(Attribute::NoUnderline)).unwrap();
+            queue!(writer, SetAttribute(Attribute::NoUnderline))?;
         }
 
         if removed.contains(Modifier::BLINK) {
-            queue!(writer, SetAttribute(Attribute::NoBlink)).unwrap();
+            queue!(writer, SetAttribute(Attribute::NoBlink))?;
         }
 
         if removed.contains(Modifier::INVERSE) {
-            queue!(writer, SetAttribute(Attribute::NoReverse)).unwrap();
+            queue!(writer, SetAttribute(Attribute::NoReverse))?;
         }
 
         if removed.contains(Modifier::HIDDEN) {
-            queue!(writer, SetAttribute(Attribute::NoHidden)).unwrap();
+            queue!(writer, SetAttribute(Attribute::NoHidden))?;
         }
 
         if removed.contains(Modifier::STRIKETHROUGH) {
-            queue!(writer, SetAttribute(Attribute::NotCrossedOut)).unwrap();
+            queue!(writer, SetAttribute(Attribute::NotCrossedOut))?;
         }
 
         if added.contains(Modifier::BOLD) {
-            queue!(writer, SetAttribute(Attribute::Bold)).unwrap();
+            queue!(writer, SetAttribute(Attribute::Bold))?;
         }
 
         if added.contains(Modifier::DIM) {
-            queue!(writer, SetAttribute(Attribute::Dim)).unwrap();
+            queue!(writer, SetAttribute(Attribute::Dim))?;
         }
 
         if added.contains(Modifier::ITALIC) {
-            queue!(writer, SetAttribute(Attribute::Italic)).unwrap();
+            queue!(writer, SetAttribute(Attribute::Italic))?;
         }
 
         if added.contains(Modifier::UNDERLINE) {
-            queue!(writer, SetAttribute(Attribute::Underlined)).unwrap();
+            queue!(writer, SetAttribute(Attribute::Underlined))?;
         }
 
         if added.contains(Modifier::BLINK) {
-            queue!(writer, SetAttribute(Attribute::SlowBlink)).unwrap();
+            queue!(writer, SetAttribute(Attribute::SlowBlink))?;
         }
 
         if added.contains(Modifier::INVERSE) {
-            queue!(writer, SetAttribute(Attribute::Reverse)).unwrap();
+            queue!(writer, SetAttribute(Attribute::Reverse))?;
         }
 
         if added.contains(Modifier::HIDDEN) {
-            queue!(writer, SetAttribute(Attribute::Hidden)).unwrap();
+            queue!(writer, SetAttribute(Attribute::Hidden))?;
         }
 
         if added.contains(Modifier::STRIKETHROUGH) {
-            queue!(writer, SetAttribute(Attribute::CrossedOut)).unwrap();
+            queue!(writer, SetAttribute(Attribute::CrossedOut))?;
         }
+        Ok(())
     }
 }
 
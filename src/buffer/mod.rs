@@ -6,6 +6,7 @@ pub use cell::*;
 pub use color::*;
 pub use size::*;
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Buffer {
@@ -17,13 +18,27 @@ impl Buffer {
     pub fn new(width: u16, height: u16) -> Self {
         Self {
             size: Size { width, height },
-            cells: vec![Cell::default(); (height * width) as usize],
+            cells: vec![Cell::default(); height as usize * width as usize],
         }
     }
 
+    /// Returns the cell at `x`, `y`, or `None` if it is outside the buffer.
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        self.index_in_bounds(x, y).map(|index| &self.cells[index])
+    }
+
+    /// Returns the cell at `x`, `y` for writing.
+    ///
+    /// Panics if the cell is outside the buffer. See [`Buffer::try_get_mut`].
     pub fn get_mut(&mut self, x: u16, y: u16) -> &mut Cell {
-        let index = self.point_to_index(x, y, self.size.width);
-        &mut self.cells[index as usize]
+        let index = y as usize * self.size.width as usize + x as usize;
+        &mut self.cells[index]
+    }
+
+    /// Returns the cell at `x`, `y` for writing, or `None` if it is outside the buffer.
+    pub fn try_get_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
+        self.index_in_bounds(x, y)
+            .map(|index| &mut self.cells[index])
     }
 
     pub fn set_char_at(
@@ -35,15 +50,30 @@ impl Buffer {
         bg: Option<Color>,
         modifier: Modifier,
     ) {
-        let cell = self
-            .get_mut(x, y)
-            .set_symbol(ch.to_string().as_ref())
-            .set_fg(fg)
-            .set_modifier(modifier);
+        self.set_clipped_char_at(None, x, y, ch, fg, bg, modifier);
+    }
 
-        bg.map(|bg| cell.set_bg(bg));
+    /// Like [`Buffer::set_char_at`], but drops the write if it is outside `clip`, such as
+    /// the clip rect of the view's [`Context`](crate::Context).
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_clipped_char_at(
+        &mut self,
+        clip: Option<Rect>,
+        x: u16,
+        y: u16,
+        ch: char,
+        fg: Color,
+        bg: Option<Color>,
+        modifier: Modifier,
+    ) {
+        let width = ch.width().unwrap_or(0).max(1) as u16;
+        let symbol = ch.to_string();
+        self.put(clip, x, y, &symbol, width, fg, bg, modifier);
     }
 
+    /// Writes `s` from `x`, `y`, stopping before the first grapheme that would not fit in
+    /// `max_width` columns. Wide graphemes take up two cells.
+    #[allow(clippy::too_many_arguments)]
     pub fn set_string_at(
         &mut self,
         x: u16,
@@ -54,20 +84,98 @@ impl Buffer {
         bg: Option<Color>,
         modifier: Modifier,
     ) {
-        let width = self.size.width;
-        let mut index = self.point_to_index(x, y, width) as usize;
-        let end_index = index + max_width as usize;
+        self.set_clipped_string_at(None, x, y, max_width, s, fg, bg, modifier);
+    }
 
+    /// Like [`Buffer::set_string_at`], but drops the graphemes that are outside `clip`,
+    /// such as the clip rect of the view's [`Context`](crate::Context).
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_clipped_string_at(
+        &mut self,
+        clip: Option<Rect>,
+        x: u16,
+        y: u16,
+        max_width: u16,
+        s: &str,
+        fg: Color,
+        bg: Option<Color>,
+        modifier: Modifier,
+    ) {
+        let mut column: u16 = 0;
         for grapheme in s.graphemes(true) {
-            if index >= end_index {
+            let width = grapheme.width().min(u16::MAX as usize) as u16;
+            if width == 0 {
+                continue;
+            }
+            if column.saturating_add(width) > max_width {
                 break;
             }
-            let cell = &mut self.cells[index];
-            cell.set_symbol(grapheme).set_fg(fg).set_modifier(modifier);
+            let Some(x) = x.checked_add(column) else {
+                break;
+            };
+            self.put(clip, x, y, grapheme, width, fg, bg, modifier);
+            column += width;
+        }
+    }
+
+    /// Writes a grapheme `width` cells wide, marking the cells after the first as
+    /// continuations. A grapheme that is cut off by the buffer or `clip` is replaced
+    /// with a space, so it never spills into cells it may not write.
+    #[allow(clippy::too_many_arguments)]
+    fn put(
+        &mut self,
+        clip: Option<Rect>,
+        x: u16,
+        y: u16,
+        symbol: &str,
+        width: u16,
+        fg: Color,
+        bg: Option<Color>,
+        modifier: Modifier,
+    ) {
+        if self.writable_index(clip, x, y).is_none() {
+            return;
+        }
+        let fits = (1..width).all(|offset| {
+            x.checked_add(offset)
+                .is_some_and(|x| self.writable_index(clip, x, y).is_some())
+        });
+        let (symbol, width) = if fits { (symbol, width) } else { (" ", 1) };
+
+        for offset in 0..width {
+            let x = x + offset;
+            self.split_wide_character(x, y);
+            let cell = self.get_mut(x, y);
+            let symbol = if offset == 0 { symbol } else { CONTINUATION };
+            cell.set_symbol(symbol).set_fg(fg).set_modifier(modifier);
             if let Some(bg_color) = bg {
                 cell.set_bg(bg_color);
             }
-            index += 1;
+        }
+    }
+
+    /// Blanks the other cells of a wide character that a write to `x`, `y` would split.
+    fn split_wide_character(&mut self, x: u16, y: u16) {
+        let Some(index) = self.index_in_bounds(x, y) else {
+            return;
+        };
+        let row_start = index - x as usize;
+        let row_end = row_start + self.size.width as usize;
+
+        if self.cells[index].is_continuation() {
+            for cell in self.cells[row_start..index].iter_mut().rev() {
+                let is_continuation = cell.is_continuation();
+                cell.set_symbol(" ");
+                if !is_continuation {
+                    break;
+                }
+            }
+        }
+        for cell in self.cells[index + 1..row_end].iter_mut() {
+            if !cell.is_continuation() {
+                break;
+            }
+            cell.set_symbol(" ");
         }
     }
 
@@ -105,13 +213,23 @@ impl Buffer {
     }
 
     #[inline]
-    fn index_to_point(&self, index: u16, width: u16) -> (u16, u16) {
-        (index % width, index / width)
+    fn index_to_point(&self, index: usize) -> (u16, u16) {
+        let width = self.size.width as usize;
+        ((index % width) as u16, (index / width) as u16)
     }
 
     #[inline]
-    fn point_to_index(&self, x: u16, y: u16, width: u16) -> u16 {
-        y * width + x
+    fn index_in_bounds(&self, x: u16, y: u16) -> Option<usize> {
+        (x < self.size.width && y < self.size.height)
+            .then(|| y as usize * self.size.width as usize + x as usize)
+    }
+
+    #[inline]
+    fn writable_index(&self, clip: Option<Rect>, x: u16, y: u16) -> Option<usize> {
+        if clip.is_some_and(|clip| !clip.contains(x, y)) {
+            return None;
+        }
+        self.index_in_bounds(x, y)
     }
 
     pub fn clear(&mut self) {
@@ -136,7 +254,7 @@ impl Buffer {
 
         for (i, (current, previous)) in self.cells.iter().zip(&previous.cells).enumerate() {
             if current != previous {
-                let (x, y) = self.index_to_point(i as u16, self.size.width);
+                let (x, y) = self.index_to_point(i);
                 updates.push((x, y, current));
             }
         }
@@ -144,3 +262,85 @@ impl Buffer {
         updates
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_writes_outside_the_buffer_are_dropped() {
+        let mut buffer = Buffer::new(4, 2);
+        buffer.set_char_at(4, 0, 'x', Color::Reset, None, Modifier::empty());
+        buffer.set_char_at(0, 2, 'x', Color::Reset, None, Modifier::empty());
+        buffer.set_string_at(2, 1, 10, "abcdef", Color::Reset, None, Modifier::empty());
+        buffer.set_string_at(u16::MAX, 0, 4, "abc", Color::Reset, None, Modifier::empty());
+        assert!(buffer.try_get_mut(4, 1).is_none());
+        assert_eq!(buffer.as_plain_str(), "    \n  ab");
+    }
+
+    #[test]
+    fn test_large_buffers_index_past_u16() {
+        let mut buffer = Buffer::new(400, 200);
+        buffer.set_char_at(399, 199, 'x', Color::Reset, None, Modifier::empty());
+        assert_eq!(buffer.cells.len(), 80_000);
+        assert_eq!(buffer.cells.last().unwrap().symbol, "x");
+
+        let previous = Buffer::new(400, 200);
+        let diff = buffer.diff(&previous);
+        assert_eq!(diff.len(), 1);
+        assert_eq!((diff[0].0, diff[0].1), (399, 199));
+    }
+
+    fn plain(buffer: &Buffer) -> Vec<&str> {
+        buffer.cells.iter().map(|cell| &cell.symbol[..]).collect()
+    }
+
+    #[test]
+    fn test_wide_graphemes_take_two_cells() {
+        let mut buffer = Buffer::new(6, 1);
+        buffer.set_string_at(0, 0, 6, "日本a", Color::Reset, None, Modifier::empty());
+        assert_eq!(plain(&buffer), ["日", "", "本", "", "a", " "]);
+        assert!(buffer.get(1, 0).unwrap().is_continuation());
+        assert_eq!(buffer.as_plain_str(), "日本a ");
+    }
+
+    #[test]
+    fn test_wide_grapheme_that_does_not_fit_is_not_split() {
+        let mut buffer = Buffer::new(6, 1);
+        buffer.set_string_at(0, 0, 3, "日本", Color::Reset, None, Modifier::empty());
+        assert_eq!(plain(&buffer), ["日", "", " ", " ", " ", " "]);
+
+        buffer.set_string_at(5, 0, 2, "本", Color::Reset, None, Modifier::empty());
+        assert_eq!(plain(&buffer), ["日", "", " ", " ", " ", " "]);
+    }
+
+    #[test]
+    fn test_wide_grapheme_cut_off_by_the_clip_is_not_split() {
+        let mut buffer = Buffer::new(4, 1);
+        let clip = Some(Rect::new(0, 0, 3, 1));
+        let (fg, modifier) = (Color::Reset, Modifier::empty());
+        buffer.set_clipped_string_at(clip, 0, 0, 4, "ab日", fg, None, modifier);
+        assert_eq!(plain(&buffer), ["a", "b", " ", " "]);
+    }
+
+    #[test]
+    fn test_overwriting_half_of_a_wide_grapheme_blanks_the_other_half() {
+        let mut buffer = Buffer::new(6, 1);
+        buffer.set_string_at(0, 0, 6, "日本語", Color::Reset, None, Modifier::empty());
+        buffer.set_char_at(1, 0, 'a', Color::Reset, None, Modifier::empty());
+        buffer.set_char_at(2, 0, 'b', Color::Reset, None, Modifier::empty());
+        assert_eq!(plain(&buffer), [" ", "a", "b", " ", "語", ""]);
+    }
+
+    #[test]
+    fn test_writes_are_clipped() {
+        let mut buffer = Buffer::new(6, 2);
+        let clip = Some(Rect::new(1, 0, 4, 2).intersection(Rect::new(0, 1, 6, 1)));
+        let (fg, modifier) = (Color::Reset, Modifier::empty());
+        buffer.set_clipped_string_at(clip, 0, 0, 6, "abcdef", fg, None, modifier);
+        buffer.set_clipped_string_at(clip, 0, 1, 6, "ghijkl", fg, None, modifier);
+        buffer.set_clipped_char_at(clip, 0, 1, 'x', fg, None, modifier);
+        assert_eq!(buffer.as_plain_str(), "      \n hijk ");
+    }
+}
@@ -43,12 +43,26 @@ impl Rect {
 
     #[inline]
     pub fn right(&self) -> u16 {
-        self.left() + self.size.width
+        self.left().saturating_add(self.size.width)
     }
 
     #[inline]
     pub fn bottom(&self) -> u16 {
-        self.top() + self.size.height
+        self.top().saturating_add(self.size.height)
+    }
+
+    /// Returns true if the cell at `x`, `y` is inside the rect.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        (self.left()..self.right()).contains(&x) && (self.top()..self.bottom()).contains(&y)
+    }
+
+    /// Returns the area covered by both rects, which is empty if they don't overlap.
+    pub fn intersection(&self, other: Rect) -> Rect {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right()).max(left);
+        let bottom = self.bottom().min(other.bottom()).max(top);
+        Rect::new(left, top, right - left, bottom - top)
     }
 
     pub fn inset_by(
@@ -192,6 +206,21 @@ mod tests {
         assert_eq!(inset_rect.size.height, 10);
     }
 
+    #[test]
+    fn test_rect_intersection() {
+        let rect = Rect::new(10, 10, 30, 20);
+        assert_eq!(
+            rect.intersection(Rect::new(0, 20, 20, 40)),
+            Rect::new(10, 20, 10, 10)
+        );
+        assert_eq!(rect.intersection(Rect::new(50, 0, 5, 5)).size, Size::zero());
+        assert_eq!(
+            Rect::new(u16::MAX - 1, 0, 10, 1).right(),
+            u16::MAX,
+            "right saturates instead of overflowing"
+        );
+    }
+
     #[test]
     fn test_rect_outset_by() {
         let rect = Rect::new(10, 10, 30, 20);
@@ -0,0 +1,40 @@
+use std::{fmt::Display, io};
+
+/// Why an app stopped without producing its output.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The user pressed a quit key, such as Ctrl-C.
+    Interrupted,
+    /// There is no terminal to draw to, for example when running in CI or when stdout is
+    /// redirected. Use [`Output::Tty`](crate::Output::Tty) to draw to the terminal while stdout
+    /// is redirected.
+    NoTerminal,
+    /// Reading from or writing to the terminal failed.
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Interrupted => write!(f, "the app was interrupted"),
+            Error::NoTerminal => write!(f, "no terminal is attached"),
+            Error::Io(error) => write!(f, "terminal error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
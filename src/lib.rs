@@ -1,9 +1,11 @@
 pub mod buffer;
 pub mod direction;
+pub mod error;
 pub mod terminal_app;
 pub mod view;
 
 pub use buffer::Color;
+pub use error::Error;
 pub use terminal_app::*;
 use unicode_width::UnicodeWidthStr;
 pub use view::*;
@@ -13,10 +15,15 @@ use std::{any::Any, sync::Arc};
 #[cfg(test)]
 mod tests;
 
-pub use crossterm::event::{KeyCode, KeyEvent};
+pub use crossterm::event::{
+    KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 
 /// Creates a text view
 ///
+/// Lines are broken at each `\n`, and wrapped between words when they are wider
+/// than the frame. See [`Text::wrap`] and [`Text::truncate`].
+///
 /// # Examples
 /// ```
 /// use altar::*;
@@ -28,12 +35,117 @@ pub use crossterm::event::{KeyCode, KeyEvent};
 /// Hello There
 pub fn text<S: AsRef<str>>(text: S) -> Text {
     let ref_text = text.as_ref();
+    let width = ref_text.split('\n').map(|line| line.width()).max();
     Text {
         text: ref_text.into(),
-        width: ref_text.width() as u16,
+        width: width.unwrap_or(0).min(u16::MAX as usize) as u16,
+        wrap: Wrap::Word,
+        truncation: None,
     }
 }
 
+/// Creates a run of text with its own style, for use in [`styled_text()`]
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// let span = span("error").red().bold();
+/// ```
+pub fn span<S: AsRef<str>>(text: S) -> Span {
+    Span::new(text)
+}
+
+/// Creates a text view from differently styled spans
+///
+/// The spans wrap together as a single piece of text.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// let view = styled_text([
+///     span("Build "),
+///     span("failed").red().bold(),
+///     span(" after 3 attempts"),
+/// ]);
+/// ```
+///
+/// Rendered Output
+/// ---------------
+/// Build failed after 3 attempts
+pub fn styled_text(spans: impl IntoIterator<Item = Span>) -> StyledText {
+    StyledText::new(spans)
+}
+
+/// Creates a styled text view from a string containing ANSI escape codes
+///
+/// SGR sequences are mapped onto colors and modifiers, so output captured from
+/// tools like `cargo` or `git` keeps its colors. Any other escape sequences are
+/// dropped.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// let view = ansi_text("\x1b[1;32mFinished\x1b[0m release profile");
+/// ```
+///
+/// Rendered Output
+/// ---------------
+/// Finished release profile
+pub fn ansi_text<S: AsRef<str>>(text: S) -> StyledText {
+    StyledText::new(view::ansi::parse_ansi(text.as_ref()))
+}
+
+/// Creates an editable single-line text field
+///
+/// The field reports every edit through `on_change`, which turns the new text
+/// into a message for the app.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// enum Message {
+///     NameChanged(String),
+///     Submit,
+/// }
+///
+/// let name = String::from("Ferris");
+/// let view = text_field(&name, Message::NameChanged)
+///     .placeholder("Your name")
+///     .on_submit(|_| Message::Submit);
+/// ```
+pub fn text_field<M, F>(value: impl Into<String>, on_change: F) -> TextField<M>
+where
+    M: Send + 'static,
+    F: Fn(String) -> M + Send + Sync + 'static,
+{
+    TextField::new(value, on_change)
+}
+
+/// Creates an editable multi-line text area
+///
+/// Like [`text_field()`], the text area reports every edit through `on_change`.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// enum Message {
+///     NotesChanged(String),
+/// }
+///
+/// let notes = String::from("First line\nSecond line");
+/// let view = text_area(&notes, Message::NotesChanged)
+///     .placeholder("Notes")
+///     .tab_width(2)
+///     .fill();
+/// ```
+pub fn text_area<M, F>(value: impl Into<String>, on_change: F) -> TextArea<M>
+where
+    M: Send + 'static,
+    F: Fn(String) -> M + Send + Sync + 'static,
+{
+    TextArea::new(value, on_change)
+}
+
 /// Creates a vertical stack view
 ///
 /// # Examples
@@ -101,6 +213,42 @@ pub fn zstack<VT: ViewTuple>(children: VT) -> ZStack<VT> {
     }
 }
 
+/// Creates a vertically scrolling list of views
+///
+/// The scroll offset is kept between renders, so the list only scrolls when
+/// the child passed to `scroll_to` would otherwise be out of view.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// let items = (0..100)
+///     .map(|i| text(format!("Item {}", i)).id(i))
+///     .collect::<Vec<_>>();
+/// let view = scroll_view(items).scroll_to(42).scrollbar(true);
+/// ```
+pub fn scroll_view<VT: ViewTuple>(children: VT) -> ScrollView<VT> {
+    ScrollView::new(children, Axis::Vertical)
+}
+
+/// Creates a vertical list that only builds the rows it renders
+///
+/// Use this instead of `vstack` for long lists, such as logs with tens of
+/// thousands of lines.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// let lines = vec!["one", "two", "three"];
+/// let view = lazy_vstack(lines.len(), move |i| text(lines[i]));
+/// ```
+pub fn lazy_vstack<F, V>(count: usize, row: F) -> LazyVStack<F>
+where
+    F: Fn(usize) -> V,
+    V: View,
+{
+    LazyVStack::new(count, row)
+}
+
 /// Creates a view given a function that's passed the current size of the view
 pub fn with_size<F, V>(f: F) -> GeometryReader<F>
 where
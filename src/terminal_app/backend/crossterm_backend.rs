@@ -0,0 +1,119 @@
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor::MoveTo,
+    event::EventStream,
+    queue,
+    style::{Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{self, ScrollUp},
+};
+use futures::StreamExt;
+use unicode_width::UnicodeWidthStr;
+
+use super::{Backend, ClearType, TerminalEvents};
+use crate::{
+    buffer::{Cell, Modifier, Size},
+    terminal_app::{raw_mode_guard::RawModeGuard, OutputWriter},
+    Color, Error, RunOptions,
+};
+
+/// Draws to the terminal with crossterm. This is the backend used by `run`.
+///
+/// Creating it puts the terminal in raw mode and applies the [`RunOptions`], such as
+/// entering the alternate screen. Dropping it restores the terminal.
+pub struct CrosstermBackend {
+    writer: OutputWriter,
+    _guard: RawModeGuard,
+}
+
+impl CrosstermBackend {
+    /// Takes over the terminal chosen by [`RunOptions::output`].
+    ///
+    /// Fails with [`Error::NoTerminal`] if there is no terminal to draw to.
+    pub fn new(options: &RunOptions) -> Result<Self, Error> {
+        let writer = options.output.open()?;
+        let guard = RawModeGuard::new(options)?;
+        Ok(Self {
+            writer,
+            _guard: guard,
+        })
+    }
+}
+
+impl Backend for CrosstermBackend {
+    fn draw<'a, I>(&mut self, cells: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let mut last_fg = Color::Reset;
+        let mut last_bg = Color::Reset;
+        let mut last_modifier = Modifier::empty();
+        // Where the terminal's cursor is after the last print
+        let mut cursor = (u16::MAX, u16::MAX);
+
+        for (x, y, cell) in cells {
+            if cell.fg != last_fg {
+                queue!(self.writer, SetForegroundColor(cell.fg))?;
+                last_fg = cell.fg;
+            }
+
+            if cell.bg != last_bg {
+                queue!(self.writer, SetBackgroundColor(cell.bg))?;
+                last_bg = cell.bg;
+            }
+
+            if cell.modifier != last_modifier {
+                Modifier::write_diff(cell.modifier, last_modifier, &mut self.writer)?;
+                last_modifier = cell.modifier;
+            }
+
+            if cursor != (x, y) {
+                queue!(self.writer, MoveTo(x, y))?;
+            }
+            queue!(self.writer, Print(&cell.symbol))?;
+
+            cursor = (x.saturating_add(cell.symbol.width() as u16), y);
+        }
+
+        // Reset colors and attributes at the end
+        queue!(
+            self.writer,
+            SetForegroundColor(crossterm::style::Color::Reset),
+            SetBackgroundColor(crossterm::style::Color::Reset),
+            SetAttribute(crossterm::style::Attribute::Reset),
+        )
+    }
+
+    fn move_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        queue!(self.writer, MoveTo(x, y))
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        self.writer.cursor_position()
+    }
+
+    fn scroll_up(&mut self, lines: u16) -> io::Result<()> {
+        queue!(self.writer, ScrollUp(lines))
+    }
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let clear_type = match clear_type {
+            ClearType::All => terminal::ClearType::All,
+            ClearType::FromCursorDown => terminal::ClearType::FromCursorDown,
+        };
+        queue!(self.writer, terminal::Clear(clear_type))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        let (width, height) = terminal::size()?;
+        Ok(Size::new(width, height))
+    }
+
+    fn events(&mut self) -> TerminalEvents {
+        EventStream::new().boxed()
+    }
+}
@@ -0,0 +1,60 @@
+use std::io;
+
+use futures::stream::BoxStream;
+
+use crate::buffer::{Cell, Size};
+
+pub use crossterm::event::Event as TerminalEvent;
+
+pub use crossterm_backend::*;
+
+pub mod crossterm_backend;
+
+/// The events read from a terminal, see [`Backend::events`].
+pub type TerminalEvents = BoxStream<'static, io::Result<TerminalEvent>>;
+
+/// What [`Backend::clear`] erases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClearType {
+    /// The whole screen.
+    All,
+    /// Everything from the cursor to the end of the screen.
+    FromCursorDown,
+}
+
+/// The terminal an app is drawn to and reads events from.
+///
+/// Apps draw through [`CrosstermBackend`] by default. Implement this trait to run an app
+/// on another terminal library, or to record what it draws; pass your backend to
+/// [`AsyncTerminalAppExt::run_with_backend`](crate::AsyncTerminalAppExt::run_with_backend).
+///
+/// A backend sets up the terminal when it is created (raw mode, the alternate screen and
+/// so on) and restores it when dropped. The renderer decides what to draw, and calls
+/// [`Backend::flush`] at the end of each frame.
+pub trait Backend {
+    /// Draws cells at their `x`, `y` positions, in order. Cells covered by a wide
+    /// character to their left are left out.
+    fn draw<'a, I>(&mut self, cells: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>;
+
+    fn move_cursor(&mut self, x: u16, y: u16) -> io::Result<()>;
+
+    /// Returns the cursor's position. Backends that can't tell should fail with
+    /// [`io::ErrorKind::Unsupported`], and inline apps assume the cursor is at the bottom.
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)>;
+
+    /// Scrolls the screen's contents up by `lines`, leaving blank lines at the bottom.
+    fn scroll_up(&mut self, lines: u16) -> io::Result<()>;
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()>;
+
+    /// Writes out everything drawn since the last flush.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Returns the size of the screen in cells.
+    fn size(&self) -> io::Result<Size>;
+
+    /// Returns a stream of key, mouse and resize events. It is called once per run.
+    fn events(&mut self) -> TerminalEvents;
+}
@@ -0,0 +1,171 @@
+use std::{fmt::Debug, future::Future, ops::ControlFlow};
+
+use futures::{future::BoxFuture, stream::FuturesUnordered, FutureExt};
+use tokio::task::JoinSet;
+
+use crate::Error;
+
+/// The side effects an app asks for, returned from
+/// [`AsyncTerminalApp::init`](crate::AsyncTerminalApp::init) and
+/// [`AsyncTerminalApp::update`](crate::AsyncTerminalApp::update).
+///
+/// Futures started with [`Command::perform`] are run by the app's run loop, and the
+/// message each produces is passed to `update`. Any still running when the app exits are
+/// cancelled.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// use std::time::Duration;
+///
+/// enum Message {
+///     Loaded(String),
+/// }
+///
+/// let load: Command<Message> = Command::perform(async {
+///     tokio::time::sleep(Duration::from_millis(100)).await;
+///     Message::Loaded("done".into())
+/// });
+/// let quit: Command<Message, u32> = Command::exit(42);
+/// ```
+#[must_use = "commands do nothing unless returned to the run loop"]
+pub struct Command<M, T = ()> {
+    pub(crate) futures: Vec<BoxFuture<'static, M>>,
+    pub(crate) exit: Option<T>,
+}
+
+impl<M, T> Command<M, T> {
+    /// Does nothing, leaving the app running.
+    pub fn none() -> Self {
+        Self {
+            futures: Vec::new(),
+            exit: None,
+        }
+    }
+
+    /// Runs `future` in the background, then passes its message to `update`.
+    pub fn perform(future: impl Future<Output = M> + Send + 'static) -> Self {
+        Self {
+            futures: vec![future.boxed()],
+            exit: None,
+        }
+    }
+
+    /// Stops the app, returning `output` from `run`.
+    pub fn exit(output: T) -> Self {
+        Self {
+            futures: Vec::new(),
+            exit: Some(output),
+        }
+    }
+
+    /// Combines commands into one. If more than one exits, the first output is returned.
+    pub fn batch(commands: impl IntoIterator<Item = Self>) -> Self {
+        commands
+            .into_iter()
+            .fold(Self::none(), |mut batch, command| {
+                batch.futures.extend(command.futures);
+                batch.exit = batch.exit.or(command.exit);
+                batch
+            })
+    }
+
+    /// Returns true if the command neither performs anything nor exits.
+    pub fn is_none(&self) -> bool {
+        self.futures.is_empty() && self.exit.is_none()
+    }
+
+    /// Returns the output the command exits with, if it exits.
+    pub fn exit_output(&self) -> Option<&T> {
+        self.exit.as_ref()
+    }
+
+    /// Returns the futures started by [`Command::perform`], to run them yourself in tests.
+    pub fn into_futures(self) -> Vec<BoxFuture<'static, M>> {
+        self.futures
+    }
+
+    /// Starts the command's futures, then breaks if the command exits.
+    pub(crate) fn start(self, executor: &mut impl Executor<M>) -> ControlFlow<Result<T, Error>> {
+        for future in self.futures {
+            executor.spawn(future);
+        }
+        match self.exit {
+            Some(output) => ControlFlow::Break(Ok(output)),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+
+impl<M, T> Default for Command<M, T> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<M, T: Debug> Debug for Command<M, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Command")
+            .field("futures", &self.futures.len())
+            .field("exit", &self.exit)
+            .finish()
+    }
+}
+
+/// Runs the futures from commands: spawned on tokio by the run loop, or held by the
+/// test harness until it is asked for the next message.
+pub(crate) trait Executor<M> {
+    fn spawn(&mut self, future: BoxFuture<'static, M>);
+}
+
+impl<M: Send + 'static> Executor<M> for JoinSet<M> {
+    fn spawn(&mut self, future: BoxFuture<'static, M>) {
+        JoinSet::spawn(self, future);
+    }
+}
+
+impl<M> Executor<M> for FuturesUnordered<BoxFuture<'static, M>> {
+    fn spawn(&mut self, future: BoxFuture<'static, M>) {
+        self.push(future);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_batch_keeps_futures_and_first_exit() {
+        let command: Command<u8, &str> = Command::batch([
+            Command::perform(async { 1 }),
+            Command::none(),
+            Command::exit("first"),
+            Command::perform(async { 2 }),
+            Command::exit("second"),
+        ]);
+        assert_eq!(command.exit_output(), Some(&"first"));
+
+        let messages: Vec<u8> = command.into_futures().into_iter().map(block_on).collect();
+        assert_eq!(messages, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_start_spawns_futures_then_breaks_on_exit() {
+        let mut tasks = FuturesUnordered::new();
+        let flow = Command::<u8>::perform(async { 1 }).start(&mut tasks);
+        assert!(flow.is_continue());
+        assert_eq!(tasks.len(), 1);
+
+        let flow = Command::<u8, u32>::exit(7).start(&mut tasks);
+        assert!(matches!(flow, ControlFlow::Break(Ok(7))));
+    }
+
+    #[test]
+    fn test_none() {
+        assert!(Command::<(), ()>::none().is_none());
+        assert!(!Command::<(), ()>::exit(()).is_none());
+        assert!(!Command::<(), ()>::perform(async {}).is_none());
+    }
+}
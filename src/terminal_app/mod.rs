@@ -1,30 +1,53 @@
+pub mod backend;
+mod command;
 mod raw_mode_guard;
 mod renderer;
+mod run_options;
+mod subscription;
 
-use std::time::Duration;
-use std::{io::stdout, time::Instant};
-use tokio::time::timeout;
+use std::{ops::ControlFlow, time::Duration};
+use tokio::time::{sleep_until, Instant};
 
 use crate::*;
 use async_trait::async_trait;
-use crossterm::event::{
-    Event as CrosstermEvent, EventStream, KeyCode, KeyEvent, KeyModifiers, MouseEvent,
-    MouseEventKind,
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers, MouseEventKind};
+use futures::StreamExt;
+use tokio::{
+    sync::mpsc,
+    task::{JoinError, JoinSet},
 };
-use futures::{FutureExt, StreamExt};
-use tokio::sync::mpsc;
 
-use raw_mode_guard::RawModeGuard;
 use renderer::*;
 
 use self::{fullscreen_renderer::FullScreenRenderer, inline_renderer::InlineRenderer};
 
+pub use backend::*;
+pub use command::*;
+pub use run_options::*;
+pub use subscription::*;
 pub use sync_terminal_app::*;
 pub mod sync_terminal_app;
+pub mod testing;
 
 pub enum Event<M> {
     Key(KeyEvent),
     Message(M),
+    /// A tick from the [`Subscription`] with this interval.
+    Tick(Duration),
+    /// Text pasted into the terminal, when no focused view took it (see
+    /// [`RunOptions::bracketed_paste`]).
+    Paste(String),
+    /// The terminal was resized to this size. Arrives once the renderer has been resized,
+    /// before the app is next drawn at the new size.
+    Resize(Size),
+    /// The terminal's window gained focus (see [`RunOptions::focus_change`]).
+    FocusGained,
+    /// The terminal's window lost focus, for example to pause polling until it returns.
+    FocusLost,
+    /// A mouse event, while [`RunOptions::mouse_capture`] is on. Arrives after any
+    /// messages from the views under the pointer, such as
+    /// [`on_click`](ViewExtensions::on_click).
+    Mouse(MouseEvent),
 }
 
 /// A trait representing an asynchronous terminal application.
@@ -32,38 +55,72 @@ pub trait AsyncTerminalApp {
     /// The type of messages that the application will handle.
     type Message: Send + 'static;
 
+    /// The value the application produces when it exits, such as the item chosen in a picker.
+    type Output: Send;
+
     /// Renders the current application.
     ///
     /// # Returns
     /// An implementation of the `View` trait that represents the current state of the application.
     fn render(&self) -> impl View;
 
-    /// Returns true if the application should quit.
-    // fn should_quit(&self) -> bool;
-
     /// Update the application's state based on the given event.
     ///
+    /// Key presses and pastes are offered to the focused view first (see
+    /// [`ViewExtensions::focusable`]), so only the ones it does not handle arrive here as
+    /// `Event::Key` and `Event::Paste`.
+    ///
     /// # Parameters
     /// - `event`: The event that occurred, which could be a key press or a custom message.
     /// - `sender`: A sender that can be used to send messages back to the application.
     ///
     /// # Returns
-    /// `true` if the application should continue running, `false` otherwise.
+    /// A [`Command`] for any side effects, [`Command::none`] to keep running, or
+    /// [`Command::exit`] with the value `run` returns.
     fn update(
         &mut self,
         event: Event<Self::Message>,
         sender: &mpsc::UnboundedSender<Self::Message>,
-    ) -> bool;
+    ) -> Command<Self::Message, Self::Output>;
 
     /// Initialize the application.
     ///
     /// This method is called when the terminal application is first initialized.
-    /// It can be used to set up any necessary state or start background tasks.
+    /// It can be used to set up any necessary state or start background tasks, preferably
+    /// with [`Command::perform`] so they are cancelled when the app exits.
     ///
     /// # Parameters
     /// - `sender`: A sender that can be used to send messages back to the application.
-    fn init(&mut self, sender: &mpsc::UnboundedSender<Self::Message>) {
+    fn init(
+        &mut self,
+        sender: &mpsc::UnboundedSender<Self::Message>,
+    ) -> Command<Self::Message, Self::Output> {
         let _ = sender;
+        Command::none()
+    }
+
+    /// The timers the app wants ticks from, delivered to `update` as [`Event::Tick`].
+    ///
+    /// Checked after every update, so a timer only runs while it is returned here. Return
+    /// nothing to pause ticking, for example when an animation is not on screen.
+    fn subscriptions(&self) -> Vec<Subscription> {
+        Vec::new()
+    }
+
+    /// Whether a key press should quit the app. Defaults to Ctrl-C.
+    ///
+    /// Quit keys are checked before the key is offered to the focused view, so Ctrl-D is
+    /// left for text fields to delete with. Return `false` for a key to receive it as a
+    /// normal `Event::Key` instead.
+    fn is_quit_key(&self, key: &KeyEvent) -> bool {
+        is_default_quit_key(key)
+    }
+
+    /// Called when a quit key is pressed. Return `false` to keep running, for example to
+    /// ask the user to confirm before discarding unsaved changes. Otherwise `run` returns
+    /// [`Error::Interrupted`].
+    fn should_quit(&mut self) -> bool {
+        true
     }
 
     /// Handle the application's exit.
@@ -79,149 +136,493 @@ pub trait AsyncTerminalApp {
     }
 }
 
-fn handle_event(tx: mpsc::UnboundedSender<CrosstermEvent>) -> tokio::task::JoinHandle<()> {
+/// Returns true for Ctrl-C, the key that quits an app by default.
+pub fn is_default_quit_key(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers: KeyModifiers::CONTROL,
+            ..
+        }
+    )
+}
+
+fn handle_event(
+    mut events: TerminalEvents,
+    tx: mpsc::UnboundedSender<CrosstermEvent>,
+) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        let mut reader = EventStream::new();
-        loop {
-            let crossterm_event = reader.next().fuse();
-            tokio::select! {
-                Some(Ok(event)) = crossterm_event => {
-                  if tx.send(event).is_err() {
-                      break;
-                  }
+        while let Some(event) = events.next().await {
+            if let Ok(event) = event {
+                if tx.send(event).is_err() {
+                    break;
                 }
-                else => {}
             }
         }
     })
 }
 
-fn create_renderer(use_full_screen: bool) -> SomeRenderer<std::io::Stdout> {
-    if use_full_screen {
-        SomeRenderer::FullScreen(FullScreenRenderer::new(stdout()))
+fn create_renderer<B: Backend>(options: &RunOptions, backend: B) -> Result<SomeRenderer<B>, Error> {
+    Ok(if options.full_screen {
+        SomeRenderer::FullScreen(FullScreenRenderer::new(backend)?)
     } else {
-        SomeRenderer::Inline(InlineRenderer::new(stdout()))
-    }
+        SomeRenderer::Inline(InlineRenderer::new(backend)?)
+    })
 }
 
 #[async_trait]
 pub trait AsyncTerminalAppExt: AsyncTerminalApp + Sized {
-    async fn run(&mut self, use_full_screen: bool) {
+    /// Runs the app until `update` returns [`Command::exit`], returning its value.
+    ///
+    /// Fails with [`Error::NoTerminal`] if there is no terminal to draw to.
+    async fn run(&mut self, options: RunOptions) -> Result<Self::Output, Error> {
+        let backend = CrosstermBackend::new(&options)?;
+        self.run_with_backend(options, backend).await
+    }
+
+    /// Runs the app like [`run`](AsyncTerminalAppExt::run), drawing to `backend` and
+    /// reading its events instead of the terminal's.
+    ///
+    /// Options that set up the terminal, such as [`RunOptions::output`] and
+    /// [`RunOptions::mouse_capture`], are up to the backend.
+    async fn run_with_backend<B: Backend + Send>(
+        &mut self,
+        options: RunOptions,
+        mut backend: B,
+    ) -> Result<Self::Output, Error> {
         let (message_sender, mut message_receiver) = mpsc::unbounded_channel::<Self::Message>();
         let (terminal_event_sender, mut terminal_event_receiver) =
             mpsc::unbounded_channel::<CrosstermEvent>();
 
-        let mut renderer = create_renderer(use_full_screen);
-        let _guard = RawModeGuard::new(use_full_screen);
-        let terminal_event_task = handle_event(terminal_event_sender);
+        let terminal_events = backend.events();
+        let mut renderer = create_renderer(&options, backend)?;
 
-        // Allow the application to initialize itself
-        self.init(&message_sender);
+        // Allow the application to initialize itself. The tasks started by its commands
+        // are cancelled when the loop ends.
+        let mut tasks = JoinSet::new();
+        let mut flow = self.init(&message_sender).start(&mut tasks);
 
         // Initial render
-        renderer.render(&self.render());
-
-        let collect_duration = Duration::from_millis(5);
+        renderer.render(&self.render())?;
+        let terminal_event_task = handle_event(terminal_events, terminal_event_sender);
+        let mut last_render = Instant::now();
+        let mut render_pending = false;
+        let mut timers = Timers::default();
+        timers.update(self.subscriptions());
 
-        loop {
+        let result = loop {
+            if let ControlFlow::Break(result) = flow {
+                break result;
+            }
             let mut events = Vec::new();
             let mut messages = Vec::new();
 
-            // Collect events and messages for a short duration
-            let start = Instant::now();
-            while start.elapsed() < collect_duration {
-                tokio::select! {
-                    Ok(Some(event)) = timeout(collect_duration, terminal_event_receiver.recv()) => {
-                        events.push(event);
-                    },
-                    Ok(Some(msg)) = timeout(collect_duration, message_receiver.recv()) => {
-                        messages.push(msg);
-                    },
-                    else => break,
-                }
-            }
-
-            let should_render = !events.is_empty() || !messages.is_empty();
-
-            // Process collected events
-            let mut should_continue = true;
-            for event in events {
-                if !handle_terminal_event(self, event, &message_sender, &mut renderer) {
-                    should_continue = false;
-                    break;
-                }
+            // Sleep until something arrives, a subscription ticks, or a frame held back by the
+            // frame rate is due
+            let next_frame = last_render + options.frame_interval.unwrap_or_default();
+            let next_tick = timers.next_tick();
+            tokio::select! {
+                Some(event) = terminal_event_receiver.recv() => events.push(event),
+                Some(message) = message_receiver.recv() => messages.push(message),
+                Some(result) = tasks.join_next() => messages.push(task_message(result)),
+                _ = sleep_until(next_frame), if render_pending => {}
+                _ = sleep_until(next_tick.unwrap_or(next_frame)), if next_tick.is_some() => {}
             }
+            let ticks = timers.take_due();
+            render_pending |= !ticks.is_empty();
 
-            // Process collected messages
-            for msg in messages {
-                if !self.update(Event::Message(msg), &message_sender) {
-                    should_continue = false;
-                    break;
+            // Collect the rest of a burst, such as a paste or a flood of messages, into one batch
+            if !events.is_empty() || !messages.is_empty() {
+                render_pending = true;
+                let batch_end = Instant::now() + options.batch_window;
+                loop {
+                    tokio::select! {
+                        biased;
+                        _ = sleep_until(batch_end) => break,
+                        Some(event) = terminal_event_receiver.recv() => events.push(event),
+                        Some(message) = message_receiver.recv() => messages.push(message),
+                        Some(result) = tasks.join_next() => messages.push(task_message(result)),
+                    }
                 }
             }
 
-            // Break out of the loop if necessary
-            if !should_continue {
-                break;
+            // Process the collected batch, stopping if the app exits
+            flow = handle_batch(
+                self,
+                &options,
+                ticks,
+                events,
+                messages,
+                &message_sender,
+                &mut tasks,
+                &mut renderer,
+            );
+            if flow.is_break() {
+                continue;
             }
+            timers.update(self.subscriptions());
 
-            // Render after processing the batch
-            if should_render {
-                renderer.render(&self.render());
+            // Render after processing the batch, unless the last frame was too recent
+            let frame_due = options
+                .frame_interval
+                .is_none_or(|interval| last_render.elapsed() >= interval);
+            if render_pending && frame_due {
+                if let Err(error) = renderer.render(&self.render()) {
+                    break Err(error.into());
+                }
+                send_hover_leaves(&mut renderer, &message_sender);
+                last_render = Instant::now();
+                render_pending = false;
             }
-        }
+        };
 
+        tasks.abort_all();
+        terminal_event_task.abort();
+        // The task may have finished already if the backend ran out of events
+        let _ = terminal_event_task.await;
         if let Some(view) = self.handle_exit() {
-            renderer.render(&view);
+            renderer.render(&view)?;
         }
-        renderer.move_cursor_to_bottom_of_current_view();
-        terminal_event_task.abort();
-        terminal_event_task.await.unwrap_err();
+        renderer.move_cursor_to_bottom_of_current_view()?;
+        result
     }
 }
 
 impl<T: AsyncTerminalApp> AsyncTerminalAppExt for T {}
 
+/// Sends the leave messages for hovered views that stopped rendering, to be handled
+/// like any other message.
+pub(crate) fn send_hover_leaves<M: 'static>(
+    renderer: &mut impl Renderer,
+    message_sender: &mpsc::UnboundedSender<M>,
+) {
+    for message in renderer.app_state().take_hover_leaves() {
+        let _ = message_sender.send(message);
+    }
+}
+
+/// Takes the message from a finished command task. A task that panicked resumes its panic
+/// here, as if `update` had panicked, instead of its message being silently lost. Tasks are
+/// only aborted once the run loop has ended, so no other join error is seen here.
+fn task_message<M>(result: Result<M, JoinError>) -> M {
+    match result {
+        Ok(message) => message,
+        Err(error) => std::panic::resume_unwind(error.into_panic()),
+    }
+}
+
+/// Handles the ticks that are due, then a batch of terminal events, then the messages
+/// received alongside them.
+#[allow(clippy::too_many_arguments)]
+fn handle_batch<App: AsyncTerminalApp>(
+    app: &mut App,
+    options: &RunOptions,
+    ticks: Vec<Duration>,
+    events: Vec<CrosstermEvent>,
+    messages: Vec<App::Message>,
+    message_sender: &mpsc::UnboundedSender<App::Message>,
+    executor: &mut impl Executor<App::Message>,
+    renderer: &mut impl Renderer,
+) -> ControlFlow<Result<App::Output, Error>> {
+    for interval in ticks {
+        app.update(Event::Tick(interval), message_sender)
+            .start(executor)?;
+    }
+    for event in events {
+        handle_terminal_event(app, options, event, message_sender, executor, renderer)?;
+    }
+    for message in messages {
+        app.update(Event::Message(message), message_sender)
+            .start(executor)?;
+    }
+    ControlFlow::Continue(())
+}
+
 #[inline]
-fn handle_terminal_event<App: AsyncTerminalApp>(
+pub(crate) fn handle_terminal_event<App: AsyncTerminalApp>(
     app: &mut App,
+    options: &RunOptions,
     event: CrosstermEvent,
     message_sender: &mpsc::UnboundedSender<App::Message>,
-    renderer: &mut SomeRenderer<std::io::Stdout>,
-) -> bool {
+    executor: &mut impl Executor<App::Message>,
+    renderer: &mut impl Renderer,
+) -> ControlFlow<Result<App::Output, Error>> {
     match event {
-        CrosstermEvent::Key(KeyEvent {
-            code: KeyCode::Char('c' | 'd'),
-            modifiers: KeyModifiers::CONTROL,
-            ..
-        }) => false,
-        CrosstermEvent::Key(key) => app.update(Event::Key(key), message_sender),
+        CrosstermEvent::Key(key) if app.is_quit_key(&key) && app.should_quit() => {
+            return ControlFlow::Break(Err(Error::Interrupted));
+        }
+        CrosstermEvent::Key(key) if app.is_quit_key(&key) => {}
+        CrosstermEvent::Key(key) => handle_key_event(app, key, message_sender, executor, renderer)?,
+        CrosstermEvent::Paste(text) => match renderer.app_state().dispatch_paste(&text) {
+            KeyResponse::Ignored => app
+                .update(Event::Paste(text), message_sender)
+                .start(executor)?,
+            KeyResponse::Consumed => {}
+            KeyResponse::Message(message) => app
+                .update(Event::Message(message), message_sender)
+                .start(executor)?,
+        },
         CrosstermEvent::Resize(w, h) => {
-            renderer.resize(w, h);
-            true
+            if let Err(error) = renderer.resize(w, h) {
+                return ControlFlow::Break(Err(error.into()));
+            }
+            app.update(Event::Resize(Size::new(w, h)), message_sender)
+                .start(executor)?;
         }
-        CrosstermEvent::Mouse(MouseEvent { kind, .. }) => {
-            handle_mouse_event(app, kind, message_sender)
+        CrosstermEvent::FocusGained => app
+            .update(Event::FocusGained, message_sender)
+            .start(executor)?,
+        CrosstermEvent::FocusLost => app
+            .update(Event::FocusLost, message_sender)
+            .start(executor)?,
+        CrosstermEvent::Mouse(event) => {
+            let messages = renderer.app_state().dispatch_mouse(&event);
+            for message in messages {
+                app.update(Event::Message(message), message_sender)
+                    .start(executor)?;
+            }
+            app.update(Event::Mouse(event), message_sender)
+                .start(executor)?;
+            if options.scroll_as_arrow_keys {
+                handle_scroll_event(app, event.kind, message_sender, executor)?;
+            }
         }
-        _ => true,
+    }
+    ControlFlow::Continue(())
+}
+
+/// Offers a key to the focused view, passing it on to the app if the view ignores it.
+#[inline]
+fn handle_key_event<App: AsyncTerminalApp>(
+    app: &mut App,
+    key: KeyEvent,
+    message_sender: &mpsc::UnboundedSender<App::Message>,
+    executor: &mut impl Executor<App::Message>,
+    renderer: &mut impl Renderer,
+) -> ControlFlow<Result<App::Output, Error>> {
+    match renderer.app_state().dispatch_key(&key) {
+        KeyResponse::Ignored => app.update(Event::Key(key), message_sender).start(executor),
+        KeyResponse::Consumed => ControlFlow::Continue(()),
+        KeyResponse::Message(message) => app
+            .update(Event::Message(message), message_sender)
+            .start(executor),
     }
 }
 
+/// Passes mouse wheel scrolling on to the app as Up and Down key presses.
 #[inline]
-fn handle_mouse_event<App: AsyncTerminalApp>(
+fn handle_scroll_event<App: AsyncTerminalApp>(
     app: &mut App,
     kind: MouseEventKind,
     message_sender: &mpsc::UnboundedSender<App::Message>,
-) -> bool {
-    match kind {
-        MouseEventKind::ScrollDown => app.update(
-            Event::Key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)),
-            message_sender,
-        ),
-        MouseEventKind::ScrollUp => app.update(
-            Event::Key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
-            message_sender,
-        ),
-        _ => true,
+    executor: &mut impl Executor<App::Message>,
+) -> ControlFlow<Result<App::Output, Error>> {
+    let code = match kind {
+        MouseEventKind::ScrollDown => KeyCode::Down,
+        MouseEventKind::ScrollUp => KeyCode::Up,
+        _ => return ControlFlow::Continue(()),
+    };
+    app.update(
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE)),
+        message_sender,
+    )
+    .start(executor)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+        sync::Arc,
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::testing::TestBackend;
+
+    #[derive(Default)]
+    struct Ticker {
+        ticks: usize,
+        renders: AtomicUsize,
+    }
+
+    enum Message {
+        Tick,
+        Quit,
+    }
+
+    impl AsyncTerminalApp for Ticker {
+        type Message = Message;
+        type Output = usize;
+
+        fn render(&self) -> impl View {
+            self.renders.fetch_add(1, Ordering::SeqCst);
+            text(format!("{}", self.ticks))
+        }
+
+        fn init(&mut self, _: &mpsc::UnboundedSender<Message>) -> Command<Message, usize> {
+            Command::batch([
+                Command::perform(async { Message::Tick }),
+                Command::perform(async {
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                    Message::Quit
+                }),
+            ])
+        }
+
+        fn update(
+            &mut self,
+            event: Event<Message>,
+            _: &mpsc::UnboundedSender<Message>,
+        ) -> Command<Message, usize> {
+            match event {
+                Event::Message(Message::Tick) => self.ticks += 1,
+                Event::Message(Message::Quit) => {
+                    return Command::exit(self.renders.load(Ordering::SeqCst))
+                }
+                _ => {}
+            }
+            Command::none()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_frame_held_back_by_frame_rate_is_drawn_while_idle() {
+        let options = RunOptions::new().full_screen(true).frame_rate(20);
+        let renders = Ticker::default()
+            .run_with_backend(options, TestBackend::new(4, 1))
+            .await
+            .unwrap();
+        // The first frame, then the tick, drawn once the frame interval has passed
+        assert_eq!(renders, 2);
+    }
+
+    /// Charges up on a timer until full, then quits after a while.
+    #[derive(Default)]
+    struct Charger {
+        charge: usize,
+    }
+
+    impl AsyncTerminalApp for Charger {
+        type Message = ();
+        type Output = usize;
+
+        fn render(&self) -> impl View {
+            text(format!("{}", self.charge))
+        }
+
+        fn init(&mut self, _: &mpsc::UnboundedSender<()>) -> Command<(), usize> {
+            Command::perform(tokio::time::sleep(Duration::from_millis(200)))
+        }
+
+        fn update(
+            &mut self,
+            event: Event<()>,
+            _: &mpsc::UnboundedSender<()>,
+        ) -> Command<(), usize> {
+            match event {
+                Event::Tick(_) => self.charge += 1,
+                Event::Message(()) => return Command::exit(self.charge),
+                _ => {}
+            }
+            Command::none()
+        }
+
+        fn subscriptions(&self) -> Vec<Subscription> {
+            if self.charge < 3 {
+                vec![Subscription::every(Duration::from_millis(10))]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_tick_until_they_are_dropped() {
+        let options = RunOptions::new().full_screen(true);
+        let charge = Charger::default()
+            .run_with_backend(options, TestBackend::new(4, 1))
+            .await
+            .unwrap();
+        assert_eq!(charge, 3);
+    }
+
+    #[tokio::test]
+    async fn test_harness_ticks_subscriptions() {
+        let mut harness = testing::TestHarness::new(Charger::default(), 4, 1);
+        harness.tick().tick().tick().tick();
+        harness.assert_snapshot(["3   "]);
+    }
+
+    /// Exits as soon as it starts, leaving a slow command behind.
+    struct Impatient(Arc<AtomicBool>);
+
+    impl AsyncTerminalApp for Impatient {
+        type Message = ();
+        type Output = ();
+
+        fn render(&self) -> impl View {
+            text("")
+        }
+
+        fn init(&mut self, _: &mpsc::UnboundedSender<()>) -> Command<()> {
+            let finished = self.0.clone();
+            Command::batch([
+                Command::perform(async move {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    finished.store(true, Ordering::SeqCst);
+                }),
+                Command::exit(()),
+            ])
+        }
+
+        fn update(&mut self, _: Event<()>, _: &mpsc::UnboundedSender<()>) -> Command<()> {
+            Command::none()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commands_are_cancelled_on_exit() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let options = RunOptions::new().full_screen(true);
+        Impatient(finished.clone())
+            .run_with_backend(options, TestBackend::new(4, 1))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!finished.load(Ordering::SeqCst));
+    }
+
+    /// Starts a command that panics.
+    struct Faulty;
+
+    impl AsyncTerminalApp for Faulty {
+        type Message = ();
+        type Output = ();
+
+        fn render(&self) -> impl View {
+            text("")
+        }
+
+        fn init(&mut self, _: &mpsc::UnboundedSender<()>) -> Command<()> {
+            Command::perform(async { panic!("command failed") })
+        }
+
+        fn update(&mut self, _: Event<()>, _: &mpsc::UnboundedSender<()>) -> Command<()> {
+            Command::none()
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "command failed")]
+    fn test_panicked_command_is_resumed() {
+        let _lock = crate::tests::lock_panic_hook();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let options = RunOptions::new().full_screen(true);
+        let _ = runtime.block_on(Faulty.run_with_backend(options, TestBackend::new(4, 1)));
     }
 }
@@ -1,35 +1,191 @@
-use std::io::{stdout, Write};
+use std::{
+    io::Write,
+    panic::{self, PanicHookInfo},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
 
 use crossterm::{
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture,
+    },
     queue,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen},
 };
 
+use super::{OutputWriter, RunOptions};
+use crate::Error;
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
 pub struct RawModeGuard {
-    use_full_screen: bool,
+    options: RunOptions,
+    /// Set once the terminal is restored, by either the panic hook or `drop`.
+    restored: Arc<AtomicBool>,
+    /// Our panic hook, removed when the guard is dropped.
+    panic_hook: Option<InstalledHook>,
 }
 
 impl RawModeGuard {
-    pub fn new(use_full_screen: bool) -> Self {
-        crossterm::terminal::enable_raw_mode().expect("Failed to enter raw mode");
-        if use_full_screen {
-            queue!(stdout(), crossterm::cursor::Hide, EnterAlternateScreen).unwrap();
-        } else {
-            queue!(stdout(), crossterm::cursor::Hide).unwrap();
+    pub fn new(options: &RunOptions) -> Result<Self, Error> {
+        let mut writer = options.output.open()?;
+        crossterm::terminal::enable_raw_mode()?;
+        // From here on, dropping the guard restores the terminal if setting it up fails
+        let mut guard = Self {
+            options: options.clone(),
+            restored: Arc::new(AtomicBool::new(false)),
+            panic_hook: None,
+        };
+        let (hook_options, restored) = (guard.options.clone(), guard.restored.clone());
+        guard.panic_hook = Some(InstalledHook::install(move || {
+            restore_terminal(&hook_options, &restored)
+        }));
+        if !options.show_cursor {
+            queue!(writer, crossterm::cursor::Hide)?;
+        }
+        if options.full_screen {
+            queue!(writer, EnterAlternateScreen)?;
+        }
+        if options.mouse_capture {
+            queue!(writer, EnableMouseCapture)?;
         }
-        stdout().flush().unwrap();
-        Self { use_full_screen }
+        if options.bracketed_paste {
+            queue!(writer, EnableBracketedPaste)?;
+        }
+        if options.focus_change {
+            queue!(writer, EnableFocusChange)?;
+        }
+        writer.flush()?;
+        Ok(guard)
     }
 }
 
 impl Drop for RawModeGuard {
     fn drop(&mut self) {
-        crossterm::terminal::disable_raw_mode().expect("Failed to exit raw mode");
-        if self.use_full_screen {
-            queue!(stdout(), crossterm::cursor::Show, LeaveAlternateScreen).unwrap();
-        } else {
-            queue!(stdout(), crossterm::cursor::Show, crossterm::cursor::MoveToColumn(0)).unwrap();
+        restore_terminal(&self.options, &self.restored);
+
+        // The hook can't be replaced while panicking. Ours does nothing more once the
+        // terminal is restored, besides calling the previous hook.
+        if thread::panicking() {
+            return;
+        }
+        if let Some(panic_hook) = self.panic_hook.take() {
+            panic_hook.uninstall();
+        }
+    }
+}
+
+/// A panic hook that runs before the hook it replaced, such as one that restores the
+/// terminal before a panic is reported, so the message is printed to the normal screen
+/// instead of being garbled by raw mode.
+struct InstalledHook {
+    /// The address of our boxed hook, to tell whether it is still the current hook.
+    address: usize,
+    previous: Arc<PanicHook>,
+}
+
+impl InstalledHook {
+    fn install(before: impl Fn() + Send + Sync + 'static) -> Self {
+        let previous = Arc::new(panic::take_hook());
+        let hook = previous.clone();
+        let ours: PanicHook = Box::new(move |info| {
+            before();
+            hook(info);
+        });
+        let address = hook_address(&ours);
+        panic::set_hook(ours);
+        Self { address, previous }
+    }
+
+    /// Puts back the hook we replaced, if ours is still the current hook. A hook set
+    /// after ours is left in place, still calling ours, which calls the one before it.
+    fn uninstall(self) {
+        let current = panic::take_hook();
+        if hook_address(&current) != self.address {
+            panic::set_hook(current);
+            return;
+        }
+        drop(current);
+        match Arc::try_unwrap(self.previous) {
+            Ok(previous) => panic::set_hook(previous),
+            Err(previous) => panic::set_hook(Box::new(move |info| previous(info))),
         }
-        stdout().flush().unwrap();
+    }
+}
+
+fn hook_address(hook: &PanicHook) -> usize {
+    &**hook as *const _ as *const () as usize
+}
+
+/// Leaves raw mode and undoes the changes made for `options`, unless already done.
+fn restore_terminal(options: &RunOptions, restored: &AtomicBool) {
+    if restored.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    // Errors are ignored, as there is nowhere left to report them
+    let _ = crossterm::terminal::disable_raw_mode();
+    if let Ok(mut writer) = options.output.open() {
+        let _ = write_restore(options, &mut writer);
+    }
+}
+
+fn write_restore(options: &RunOptions, writer: &mut OutputWriter) -> std::io::Result<()> {
+    if options.mouse_capture {
+        queue!(writer, DisableMouseCapture)?;
+    }
+    if options.bracketed_paste {
+        queue!(writer, DisableBracketedPaste)?;
+    }
+    if options.focus_change {
+        queue!(writer, DisableFocusChange)?;
+    }
+    if options.full_screen {
+        queue!(writer, crossterm::cursor::Show, LeaveAlternateScreen)?;
+    } else {
+        queue!(
+            writer,
+            crossterm::cursor::Show,
+            crossterm::cursor::MoveToColumn(0)
+        )?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A hook that keeps `marker` alive while it is installed.
+    fn marker_hook(marker: &Arc<()>) -> PanicHook {
+        let marker = marker.clone();
+        Box::new(move |_| {
+            let _ = &marker;
+        })
+    }
+
+    #[test]
+    fn test_panic_hook_is_uninstalled_without_dropping_other_hooks() {
+        let _lock = crate::tests::lock_panic_hook();
+
+        // Our hook is current: the hook we replaced is put back
+        let before = Arc::new(());
+        panic::set_hook(marker_hook(&before));
+        InstalledHook::install(|| {}).uninstall();
+        drop(panic::take_hook());
+        assert_eq!(Arc::strong_count(&before), 1);
+
+        // A hook was set after ours: it stays installed
+        let installed = InstalledHook::install(|| {});
+        let after = Arc::new(());
+        panic::set_hook(marker_hook(&after));
+        installed.uninstall();
+        assert_eq!(Arc::strong_count(&after), 2);
+
+        drop(panic::take_hook());
+        assert_eq!(Arc::strong_count(&after), 1);
     }
 }
@@ -1,53 +1,52 @@
 use crate::{
-    buffer::{Buffer, Modifier, Rect, Size},
-    AppState, Color, Context, View, ViewId,
+    buffer::{Buffer, Rect, Size},
+    AppState, Backend, ClearType, View,
 };
-use crossterm::{
-    cursor::MoveTo,
-    queue,
-    style::{Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
-    terminal::{Clear, ClearType},
-};
-use std::io::{self, Write};
+use std::io;
 
 use super::Renderer;
 
-pub(crate) struct FullScreenRenderer<W: Write> {
-    writer: W,
+pub(crate) struct FullScreenRenderer<B: Backend> {
+    backend: B,
     current_buffer: Buffer,
     prev_buffer: Buffer,
     terminal_size: Size,
     app_state: AppState,
 }
 
-impl<W: Write> Renderer for FullScreenRenderer<W> {
-    fn render(&mut self, view: &impl View) {
+impl<B: Backend> Renderer for FullScreenRenderer<B> {
+    fn render(&mut self, view: &impl View) -> io::Result<()> {
         self.render(view)
     }
 
-    fn resize(&mut self, terminal_width: u16, terminal_height: u16) {
-        self.resize(terminal_width, terminal_height);
+    fn resize(&mut self, terminal_width: u16, terminal_height: u16) -> io::Result<()> {
+        self.resize(terminal_width, terminal_height)
+    }
+
+    fn move_cursor_to_bottom_of_current_view(&mut self) -> io::Result<()> {
+        Ok(())
     }
 
-    fn move_cursor_to_bottom_of_current_view(&mut self) {}
+    fn app_state(&mut self) -> &mut AppState {
+        &mut self.app_state
+    }
 }
 
-impl<W: Write> FullScreenRenderer<W> {
-    /// Creates a new `FullScreenRenderer` with the given writer.
-    pub(crate) fn new(writer: W) -> Self {
-        let (terminal_width, terminal_height) = crossterm::terminal::size().unwrap();
-        let terminal_size = Size::new(terminal_width, terminal_height);
-        Self {
-            writer,
-            current_buffer: Buffer::new(terminal_width, terminal_height),
-            prev_buffer: Buffer::new(terminal_width, terminal_height),
+impl<B: Backend> FullScreenRenderer<B> {
+    /// Creates a new `FullScreenRenderer` that draws to the given backend.
+    pub(crate) fn new(backend: B) -> io::Result<Self> {
+        let terminal_size = backend.size()?;
+        Ok(Self {
+            backend,
+            current_buffer: Buffer::new(terminal_size.width, terminal_size.height),
+            prev_buffer: Buffer::new(terminal_size.width, terminal_size.height),
             terminal_size,
             app_state: AppState::new(),
-        }
+        })
     }
 
     /// Renders the given view to the terminal.
-    pub(crate) fn render(&mut self, view: &impl View) {
+    pub(crate) fn render(&mut self, view: &impl View) -> io::Result<()> {
         self.swap_buffers();
         let Size {
             width: view_width,
@@ -60,13 +59,17 @@ impl<W: Write> FullScreenRenderer<W> {
         );
 
         let rect = Rect::new(0, 0, view_width, view_height);
-        view.render(
-            &mut ViewId::empty(),
-            Context::new(rect),
-            &mut self.app_state,
-            &mut self.current_buffer,
-        );
-        self.print_buffer().unwrap();
+        self.app_state
+            .render_frame(view, rect, &mut self.current_buffer);
+        self.print_buffer()
+    }
+
+    pub(crate) fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    pub(crate) fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
     }
 
     fn swap_buffers(&mut self) {
@@ -78,45 +81,12 @@ impl<W: Write> FullScreenRenderer<W> {
         // Diff the current buffer with the previous buffer
         // Returns the x, y, and cell for each diff
         let diff = self.current_buffer.diff(&self.prev_buffer);
-
-        let mut last_fg = Color::Reset;
-        let mut last_bg = Color::Reset;
-        let mut last_modifier = Modifier::empty();
-        let (mut last_x, mut last_y) = (u16::MAX - 1, u16::MAX - 1);
-
-        for (x, y, cell) in diff {
-            if cell.fg != last_fg {
-                queue!(self.writer, SetForegroundColor(cell.fg.into()))?;
-                last_fg = cell.fg;
-            }
-
-            if cell.bg != last_bg {
-                queue!(self.writer, SetBackgroundColor(cell.bg.into()))?;
-                last_bg = cell.bg;
-            }
-
-            if cell.modifier != last_modifier {
-                Modifier::write_diff(cell.modifier, last_modifier, &mut self.writer);
-                last_modifier = cell.modifier;
-            }
-
-            if last_x + 1 != x || last_y != y {
-                queue!(self.writer, MoveTo(x, y))?;
-            }
-            queue!(self.writer, Print(&cell.symbol))?;
-
-            (last_x, last_y) = (x, y);
-        }
-
-        // Reset colors and attributes at the end
-        queue!(
-            self.writer,
-            SetForegroundColor(crossterm::style::Color::Reset),
-            SetBackgroundColor(crossterm::style::Color::Reset),
-            SetAttribute(crossterm::style::Attribute::Reset),
+        // Printing the wide character to the left already covers continuation cells
+        self.backend.draw(
+            diff.into_iter()
+                .filter(|(_, _, cell)| !cell.is_continuation()),
         )?;
-
-        self.writer.flush()?;
+        self.backend.flush()?;
         self.prev_buffer.clear();
         Ok(())
     }
@@ -125,11 +95,12 @@ impl<W: Write> FullScreenRenderer<W> {
     ///
     /// This function clears the current view and initializes new buffers
     /// with the given dimensions.
-    pub(crate) fn resize(&mut self, terminal_width: u16, terminal_height: u16) {
-        queue!(self.writer, Clear(ClearType::All)).unwrap();
+    pub(crate) fn resize(&mut self, terminal_width: u16, terminal_height: u16) -> io::Result<()> {
+        self.backend.clear(ClearType::All)?;
 
         self.current_buffer = Buffer::new(terminal_width, terminal_height);
         self.prev_buffer = Buffer::new(terminal_width, terminal_height);
         self.terminal_size = Size::new(terminal_width, terminal_height);
+        Ok(())
     }
 }
@@ -1,21 +1,15 @@
-use std::io::{self, Write};
+use std::io;
 
 use crate::{
-    buffer::{Buffer, Modifier, Rect, Size},
-    AppState, Color, ViewId,
-};
-use crossterm::{
-    cursor::{MoveTo, MoveUp},
-    style::{Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
-    terminal::{Clear, ScrollUp},
-    *,
+    buffer::{Buffer, Rect, Size},
+    AppState, Backend, ClearType,
 };
 
-use crate::{Context, View};
+use crate::View;
 
 use super::Renderer;
-pub(crate) struct InlineRenderer<W: Write> {
-    writer: W,
+pub(crate) struct InlineRenderer<B: Backend> {
+    backend: B,
     /// Double Buffer
     current_buffer: Buffer,
     prev_buffer: Buffer,
@@ -25,41 +19,50 @@ pub(crate) struct InlineRenderer<W: Write> {
     claimed_height: u16,
     /// The size of the terminal
     terminal_size: Size,
+    app_state: AppState,
 }
 
-impl<W: Write> Renderer for InlineRenderer<W> {
-    fn render(&mut self, view: &impl View) {
-        self.render(view);
+impl<B: Backend> Renderer for InlineRenderer<B> {
+    fn render(&mut self, view: &impl View) -> io::Result<()> {
+        self.render(view)
+    }
+
+    fn resize(&mut self, terminal_width: u16, terminal_height: u16) -> io::Result<()> {
+        self.resize(terminal_width, terminal_height)
     }
 
-    fn resize(&mut self, terminal_width: u16, terminal_height: u16) {
-        self.resize(terminal_width, terminal_height);
+    fn move_cursor_to_bottom_of_current_view(&mut self) -> io::Result<()> {
+        self.move_cursor_to_bottom_of_current_view()
     }
 
-    fn move_cursor_to_bottom_of_current_view(&mut self) {
-        self.move_cursor_to_bottom_of_current_view();
+    fn app_state(&mut self) -> &mut AppState {
+        &mut self.app_state
     }
 }
 
-impl<W: Write> InlineRenderer<W> {
-    /// Creates a new `Renderer` with the given writer.
-    pub(crate) fn new(writer: W) -> Self {
-        let (terminal_width, terminal_height) = crossterm::terminal::size().unwrap();
-        let terminal_size = Size::new(terminal_width, terminal_height);
-        let (_, cursor_y) = crossterm::cursor::position().unwrap();
-        let claimed_height = terminal_height.saturating_sub(cursor_y);
-        Self {
-            writer,
-            current_buffer: Buffer::new(terminal_width, terminal_height),
-            prev_buffer: Buffer::new(terminal_width, terminal_height),
+impl<B: Backend> InlineRenderer<B> {
+    /// Creates a new `Renderer` that draws to the given backend.
+    pub(crate) fn new(mut backend: B) -> io::Result<Self> {
+        let terminal_size = backend.size()?;
+        // If the backend can't find the cursor, assume it is below the last line
+        let claimed_height = match backend.cursor_position() {
+            Ok((_, cursor_y)) => terminal_size.height.saturating_sub(cursor_y),
+            Err(error) if error.kind() == io::ErrorKind::Unsupported => 0,
+            Err(error) => return Err(error),
+        };
+        Ok(Self {
+            backend,
+            current_buffer: Buffer::new(terminal_size.width, terminal_size.height),
+            prev_buffer: Buffer::new(terminal_size.width, terminal_size.height),
             view_height: 0,
             claimed_height,
             terminal_size,
-        }
+            app_state: AppState::new(),
+        })
     }
 
     /// Renders the given view to the terminal.
-    pub(crate) fn render(&mut self, view: &impl View) {
+    pub(crate) fn render(&mut self, view: &impl View) -> io::Result<()> {
         self.swap_buffers();
         let Size {
             width: view_width,
@@ -74,34 +77,35 @@ impl<W: Write> InlineRenderer<W> {
         self.claim_space(
             view_height.max(self.claimed_height),
             self.terminal_size.height,
-        );
+        )?;
 
         let start_y = self
             .terminal_size
             .height
-            .saturating_sub(self.claimed_height)
-            .max(0);
+            .saturating_sub(self.claimed_height);
         let rect = Rect::new(0, start_y, view_width, view_height);
-        view.render(
-            &mut ViewId::empty(),
-            Context::new(rect),
-            // TODO: fix
-            &mut AppState::new(),
-            &mut self.current_buffer,
-        );
+        self.app_state
+            .render_frame(view, rect, &mut self.current_buffer);
         self.view_height = view_height;
-        self.print_buffer().unwrap();
+        self.print_buffer()
     }
 
     /// Moves the cursor to the bottom of the current view.
     /// This is intended to be called before exiting the program.
-    pub(crate) fn move_cursor_to_bottom_of_current_view(&mut self) {
+    pub(crate) fn move_cursor_to_bottom_of_current_view(&mut self) -> io::Result<()> {
         let target = self
             .terminal_size
             .height
             .saturating_sub(self.claimed_height)
             + self.view_height;
-        queue!(self.writer, MoveTo(0, target), Print("\n")).unwrap();
+        // Leave a line below the view, scrolling if it is at the bottom of the terminal
+        let last_row = self.terminal_size.height.saturating_sub(1);
+        if target >= last_row {
+            self.backend.scroll_up(1)?;
+        }
+        self.backend
+            .move_cursor(0, target.saturating_add(1).min(last_row))?;
+        self.backend.flush()
     }
 
     /// Swaps the current buffer with the previous buffer.
@@ -110,10 +114,10 @@ impl<W: Write> InlineRenderer<W> {
     }
 
     /// Ensures that the terminal has enough space to render the view by adding new lines if necessary.
-    fn claim_space(&mut self, view_height: u16, terminal_height: u16) {
+    fn claim_space(&mut self, view_height: u16, terminal_height: u16) -> io::Result<()> {
         let diff = view_height.saturating_sub(self.claimed_height);
         if diff > 0 {
-            queue!(self.writer, ScrollUp(diff)).unwrap();
+            self.backend.scroll_up(diff)?;
             self.claimed_height += diff;
 
             // clear each line
@@ -121,6 +125,7 @@ impl<W: Write> InlineRenderer<W> {
                 self.prev_buffer.clear_line(y);
             }
         }
+        Ok(())
     }
 
     /// Prints the current buffer to the terminal.
@@ -128,45 +133,12 @@ impl<W: Write> InlineRenderer<W> {
         // Diff the current buffer with the previous buffer
         // Returns the x, y, and cell for each diff
         let diff = self.current_buffer.diff(&self.prev_buffer);
-
-        let mut last_fg = Color::Reset;
-        let mut last_bg = Color::Reset;
-        let mut last_modifier = Modifier::empty();
-        let (mut last_x, mut last_y) = (u16::MAX - 1, u16::MAX - 1);
-
-        for (x, y, cell) in diff {
-            if cell.fg != last_fg {
-                queue!(self.writer, SetForegroundColor(cell.fg.into()))?;
-                last_fg = cell.fg;
-            }
-
-            if cell.bg != last_bg {
-                queue!(self.writer, SetBackgroundColor(cell.bg.into()))?;
-                last_bg = cell.bg;
-            }
-
-            if cell.modifier != last_modifier {
-                Modifier::write_diff(cell.modifier, last_modifier, &mut self.writer);
-                last_modifier = cell.modifier;
-            }
-
-            if last_x + 1 != x || last_y != y {
-                queue!(self.writer, MoveTo(x, y))?;
-            }
-            queue!(self.writer, Print(&cell.symbol))?;
-
-            (last_x, last_y) = (x, y);
-        }
-
-        // Reset colors and attributes at the end
-        queue!(
-            self.writer,
-            SetForegroundColor(crossterm::style::Color::Reset),
-            SetBackgroundColor(crossterm::style::Color::Reset),
-            SetAttribute(crossterm::style::Attribute::Reset),
+        // Printing the wide character to the left already covers continuation cells
+        self.backend.draw(
+            diff.into_iter()
+                .filter(|(_, _, cell)| !cell.is_continuation()),
         )?;
-
-        self.writer.flush()?;
+        self.backend.flush()?;
         self.prev_buffer.clear();
         Ok(())
     }
@@ -175,16 +147,15 @@ impl<W: Write> InlineRenderer<W> {
     ///
     /// This function clears the current view and initializes new buffers
     /// with the given dimensions.
-    pub(crate) fn resize(&mut self, terminal_width: u16, terminal_height: u16) {
-        queue!(
-            self.writer,
-            MoveUp(self.claimed_height),
-            Clear(terminal::ClearType::FromCursorDown)
-        )
-        .unwrap();
+    pub(crate) fn resize(&mut self, terminal_width: u16, terminal_height: u16) -> io::Result<()> {
+        // Clear the view from the top of the space it claimed
+        let top = terminal_height.saturating_sub(self.claimed_height);
+        self.backend.move_cursor(0, top)?;
+        self.backend.clear(ClearType::FromCursorDown)?;
 
         self.current_buffer = Buffer::new(terminal_width, terminal_height);
         self.prev_buffer = Buffer::new(terminal_width, terminal_height);
         self.terminal_size = Size::new(terminal_width, terminal_height);
+        Ok(())
     }
 }
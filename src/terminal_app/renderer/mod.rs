@@ -1,6 +1,6 @@
-use std::io::Write;
+use std::io;
 
-use crate::View;
+use crate::{AppState, Backend, View};
 
 use self::{fullscreen_renderer::FullScreenRenderer, inline_renderer::InlineRenderer};
 
@@ -8,35 +8,43 @@ pub mod fullscreen_renderer;
 pub mod inline_renderer;
 
 pub trait Renderer {
-    fn render(&mut self, view: &impl View);
-    fn resize(&mut self, terminal_width: u16, terminal_height: u16);
-    fn move_cursor_to_bottom_of_current_view(&mut self);
+    fn render(&mut self, view: &impl View) -> io::Result<()>;
+    fn resize(&mut self, terminal_width: u16, terminal_height: u16) -> io::Result<()>;
+    fn move_cursor_to_bottom_of_current_view(&mut self) -> io::Result<()>;
+    fn app_state(&mut self) -> &mut AppState;
 }
 
-pub(crate) enum SomeRenderer<W: Write> {
-    FullScreen(FullScreenRenderer<W>),
-    Inline(InlineRenderer<W>),
+pub(crate) enum SomeRenderer<B: Backend> {
+    FullScreen(FullScreenRenderer<B>),
+    Inline(InlineRenderer<B>),
 }
 
-impl<W: Write> Renderer for SomeRenderer<W> {
-    fn render(&mut self, view: &impl View) {
+impl<B: Backend> Renderer for SomeRenderer<B> {
+    fn render(&mut self, view: &impl View) -> io::Result<()> {
         match self {
             SomeRenderer::FullScreen(ref mut renderer) => renderer.render(view),
             SomeRenderer::Inline(ref mut renderer) => renderer.render(view),
         }
     }
 
-    fn resize(&mut self, terminal_width: u16, terminal_height: u16) {
+    fn resize(&mut self, terminal_width: u16, terminal_height: u16) -> io::Result<()> {
         match self {
             SomeRenderer::FullScreen(ref mut renderer) => renderer.resize(terminal_width, terminal_height),
             SomeRenderer::Inline(ref mut renderer) => renderer.resize(terminal_width, terminal_height),
         }
     }
 
-    fn move_cursor_to_bottom_of_current_view(&mut self) {
+    fn move_cursor_to_bottom_of_current_view(&mut self) -> io::Result<()> {
         match self {
             SomeRenderer::FullScreen(ref mut renderer) => renderer.move_cursor_to_bottom_of_current_view(),
             SomeRenderer::Inline(ref mut renderer) => renderer.move_cursor_to_bottom_of_current_view(),
         }
     }
+
+    fn app_state(&mut self) -> &mut AppState {
+        match self {
+            SomeRenderer::FullScreen(ref mut renderer) => renderer.app_state(),
+            SomeRenderer::Inline(ref mut renderer) => renderer.app_state(),
+        }
+    }
 }
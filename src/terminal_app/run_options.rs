@@ -0,0 +1,232 @@
+use std::fmt::Debug;
+use std::fs::{File, OpenOptions};
+use std::io::{self, stderr, stdout, IsTerminal, Write};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use crate::Error;
+
+/// The stream an app is drawn to.
+///
+/// Key and mouse events are always read from the terminal, even when stdin is
+/// redirected, so an app can draw to the tty while its stdin and stdout are part
+/// of a pipeline.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Output {
+    #[default]
+    Stdout,
+    /// Draws to stderr, leaving stdout free for the app's result.
+    Stderr,
+    /// Opens the controlling terminal directly, like `fzf` does.
+    Tty,
+    /// Draws to a writer of your own. See [`Output::writer`].
+    Writer(SharedWriter),
+}
+
+#[cfg(unix)]
+const TTY_PATH: &str = "/dev/tty";
+#[cfg(windows)]
+const TTY_PATH: &str = "CONOUT$";
+
+impl Output {
+    /// Draws to any writer, such as a file or a socket connected to a terminal.
+    pub fn writer(writer: impl Write + Send + 'static) -> Self {
+        Output::Writer(SharedWriter(Arc::new(Mutex::new(writer))))
+    }
+
+    /// Opens the stream, failing with [`Error::NoTerminal`] if stdout or stderr is not a
+    /// terminal, or if there is no terminal to open.
+    pub(crate) fn open(&self) -> Result<OutputWriter, Error> {
+        Ok(match self {
+            Output::Stdout if !stdout().is_terminal() => return Err(Error::NoTerminal),
+            Output::Stdout => OutputWriter::Stdout(stdout()),
+            Output::Stderr if !stderr().is_terminal() => return Err(Error::NoTerminal),
+            Output::Stderr => OutputWriter::Stderr(stderr()),
+            Output::Tty => OutputWriter::Tty(
+                OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .open(TTY_PATH)
+                    .map_err(|_| Error::NoTerminal)?,
+            ),
+            Output::Writer(writer) => OutputWriter::Writer(writer.clone()),
+        })
+    }
+}
+
+/// A writer passed to [`Output::writer`], shared by the renderer and the code that
+/// restores the terminal when the app exits.
+#[derive(Clone)]
+pub struct SharedWriter(Arc<Mutex<dyn Write + Send>>);
+
+impl Debug for SharedWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedWriter").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for SharedWriter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SharedWriter {}
+
+/// A handle to the stream chosen by an [`Output`].
+pub(crate) enum OutputWriter {
+    Stdout(io::Stdout),
+    Stderr(io::Stderr),
+    Tty(File),
+    Writer(SharedWriter),
+}
+
+impl OutputWriter {
+    /// Returns the cursor's position. The query is written to stdout, so other outputs fail
+    /// with [`io::ErrorKind::Unsupported`].
+    pub(crate) fn cursor_position(&self) -> io::Result<(u16, u16)> {
+        match self {
+            OutputWriter::Stdout(_) => crossterm::cursor::position(),
+            _ => Err(io::ErrorKind::Unsupported.into()),
+        }
+    }
+}
+
+impl Write for OutputWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            OutputWriter::Stdout(writer) => writer.write(buf),
+            OutputWriter::Stderr(writer) => writer.write(buf),
+            OutputWriter::Tty(writer) => writer.write(buf),
+            OutputWriter::Writer(writer) => lock(writer).write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            OutputWriter::Stdout(writer) => writer.flush(),
+            OutputWriter::Stderr(writer) => writer.flush(),
+            OutputWriter::Tty(writer) => writer.flush(),
+            OutputWriter::Writer(writer) => lock(writer).flush(),
+        }
+    }
+}
+
+/// Locks a shared writer, carrying on if a thread panicked while holding it.
+fn lock(writer: &SharedWriter) -> MutexGuard<'_, dyn Write + Send + 'static> {
+    writer.0.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// How an app takes over the terminal while it runs.
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// use std::time::Duration;
+///
+/// let options = RunOptions::new()
+///     .full_screen(true)
+///     .mouse_capture(true)
+///     .frame_rate(30)
+///     .batch_window(Duration::from_millis(10));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub(crate) full_screen: bool,
+    pub(crate) mouse_capture: bool,
+    pub(crate) bracketed_paste: bool,
+    pub(crate) focus_change: bool,
+    pub(crate) scroll_as_arrow_keys: bool,
+    pub(crate) show_cursor: bool,
+    pub(crate) frame_interval: Option<Duration>,
+    pub(crate) output: Output,
+    pub(crate) batch_window: Duration,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunOptions {
+    /// Renders inline below the cursor, to stdout, with the mouse left to the terminal.
+    pub fn new() -> Self {
+        Self {
+            full_screen: false,
+            mouse_capture: false,
+            bracketed_paste: true,
+            focus_change: true,
+            scroll_as_arrow_keys: true,
+            show_cursor: false,
+            frame_interval: None,
+            output: Output::Stdout,
+            batch_window: Duration::from_millis(5),
+        }
+    }
+
+    /// Renders on the alternate screen, restoring the terminal's contents on exit.
+    pub fn full_screen(mut self, full_screen: bool) -> Self {
+        self.full_screen = full_screen;
+        self
+    }
+
+    /// Captures the mouse, so that views can respond to clicks and hovering
+    /// (see [`ViewExtensions::on_click`](crate::ViewExtensions::on_click)).
+    /// While captured, the terminal's own text selection is unavailable.
+    pub fn mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.mouse_capture = mouse_capture;
+        self
+    }
+
+    /// Asks the terminal to mark pasted text, so a paste arrives at once as
+    /// [`Event::Paste`](crate::Event::Paste) instead of as typed keys. On by default.
+    pub fn bracketed_paste(mut self, bracketed_paste: bool) -> Self {
+        self.bracketed_paste = bracketed_paste;
+        self
+    }
+
+    /// Also delivers mouse wheel scrolling as Up and Down key presses, after the
+    /// [`Event::Mouse`](crate::Event::Mouse) itself. On by default; turn it off to tell
+    /// scrolling apart from the arrow keys.
+    pub fn scroll_as_arrow_keys(mut self, scroll_as_arrow_keys: bool) -> Self {
+        self.scroll_as_arrow_keys = scroll_as_arrow_keys;
+        self
+    }
+
+    /// Asks the terminal to report when its window gains or loses focus, delivered as
+    /// [`Event::FocusGained`](crate::Event::FocusGained) and
+    /// [`Event::FocusLost`](crate::Event::FocusLost). On by default.
+    pub fn focus_change(mut self, focus_change: bool) -> Self {
+        self.focus_change = focus_change;
+        self
+    }
+
+    /// Leaves the terminal's cursor visible while the app runs. It is hidden by default.
+    pub fn show_cursor(mut self, show_cursor: bool) -> Self {
+        self.show_cursor = show_cursor;
+        self
+    }
+
+    /// Limits how many frames an [`AsyncTerminalApp`](crate::AsyncTerminalApp) draws per
+    /// second. By default a frame is drawn after every batch of events.
+    pub fn frame_rate(mut self, frames_per_second: u32) -> Self {
+        self.frame_interval = Some(Duration::from_secs(1) / frames_per_second.max(1));
+        self
+    }
+
+    /// Sets where the app is drawn. Use [`Output::Tty`] to draw to the terminal while
+    /// stdout is redirected, so the app's result can be piped to another command.
+    pub fn output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Sets how long an [`AsyncTerminalApp`](crate::AsyncTerminalApp) keeps collecting
+    /// events into a batch, after the first one arrives, before updating and rendering.
+    /// While nothing arrives the app sleeps. Defaults to 5ms.
+    pub fn batch_window(mut self, batch_window: Duration) -> Self {
+        self.batch_window = batch_window;
+        self
+    }
+}
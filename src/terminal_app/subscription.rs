@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// A timer an app subscribes to, see
+/// [`AsyncTerminalApp::subscriptions`](crate::AsyncTerminalApp::subscriptions).
+///
+/// # Examples
+/// ```
+/// use altar::*;
+/// use std::time::Duration;
+///
+/// let spinner = Subscription::every(Duration::from_millis(80));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Subscription {
+    pub(crate) interval: Duration,
+}
+
+impl Subscription {
+    /// Ticks once every `interval`, starting one interval after the subscription begins.
+    /// Subscriptions with the same interval share a timer.
+    pub fn every(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+struct Timer {
+    interval: Duration,
+    next: Instant,
+}
+
+/// The timers for an app's current subscriptions, scheduled by the run loop.
+#[derive(Default)]
+pub(crate) struct Timers {
+    timers: Vec<Timer>,
+}
+
+impl Timers {
+    /// Starts timers for new subscriptions and stops the ones no longer subscribed to.
+    /// Timers that are kept carry on from their last tick.
+    pub(crate) fn update(&mut self, subscriptions: Vec<Subscription>) {
+        let now = Instant::now();
+        self.timers.retain(|timer| {
+            subscriptions
+                .iter()
+                .any(|subscription| subscription.interval == timer.interval)
+        });
+        for subscription in subscriptions {
+            let interval = subscription.interval;
+            if !self.timers.iter().any(|timer| timer.interval == interval) {
+                self.timers.push(Timer {
+                    interval,
+                    next: now + interval,
+                });
+            }
+        }
+    }
+
+    /// When the next timer is due, or `None` if there are no subscriptions.
+    pub(crate) fn next_tick(&self) -> Option<Instant> {
+        self.timers.iter().map(|timer| timer.next).min()
+    }
+
+    /// Returns the intervals of the timers that are due, scheduling their next ticks.
+    /// Ticks missed while the app was busy are skipped rather than delivered in a burst.
+    pub(crate) fn take_due(&mut self) -> Vec<Duration> {
+        let now = Instant::now();
+        let mut due = Vec::new();
+        for timer in &mut self.timers {
+            if timer.next <= now {
+                due.push(timer.interval);
+                timer.next += timer.interval;
+                if timer.next <= now {
+                    timer.next = now + timer.interval;
+                }
+            }
+        }
+        due
+    }
+}
@@ -1,50 +1,116 @@
 use super::*;
 
+/// An app that handles key events synchronously.
+///
+/// Views can only send it messages that are key events, which are passed to `update`.
 pub trait SyncTerminalApp {
     fn render(&self) -> impl View;
     fn update(&mut self, event: KeyEvent);
+
+    /// Whether a key press should quit the app. Defaults to Ctrl-C.
+    /// Return `false` for a key to receive it in `update` instead.
+    fn is_quit_key(&self, key: &KeyEvent) -> bool {
+        is_default_quit_key(key)
+    }
+
+    /// Called when a quit key is pressed. Return `false` to keep running.
+    fn should_quit(&mut self) -> bool {
+        true
+    }
+
+    /// Called with pasted text that the focused view did not take. Ignored by default.
+    fn handle_paste(&mut self, text: String) {
+        let _ = text;
+    }
+
+    /// Called with mouse events, while [`RunOptions::mouse_capture`] is on. Ignored by default.
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        let _ = event;
+    }
+
     fn handle_exit(&mut self) -> Option<impl View> {
         None as Option<EmptyView>
     }
 }
 
 pub trait SyncTerminalAppExt: SyncTerminalApp {
-    fn run(&mut self, use_full_screen: bool) {
-        let mut renderer = create_renderer(use_full_screen);
-        let _guard = RawModeGuard::new(use_full_screen);
+    /// Runs the app until a quit key is pressed.
+    ///
+    /// Fails with [`Error::NoTerminal`] if there is no terminal to draw to.
+    fn run(&mut self, options: RunOptions) -> Result<(), Error> {
+        let backend = CrosstermBackend::new(&options)?;
+        self.run_with_backend(options, backend)
+    }
+
+    /// Runs the app like [`run`](SyncTerminalAppExt::run), drawing to `backend` and
+    /// reading its events instead of the terminal's. The app stops when the backend
+    /// runs out of events.
+    fn run_with_backend(
+        &mut self,
+        options: RunOptions,
+        mut backend: impl Backend,
+    ) -> Result<(), Error> {
+        let mut events = backend.events();
+        let mut renderer = create_renderer(&options, backend)?;
 
         loop {
-            renderer.render(&self.render());
-            let event = crossterm::event::read().unwrap();
-            match event {
-                CrosstermEvent::Key(KeyEvent {
-                    code: KeyCode::Char('c' | 'd'),
-                    modifiers: KeyModifiers::CONTROL,
-                    ..
-                }) => break,
-                CrosstermEvent::Mouse(MouseEvent { kind, .. }) => match kind {
-                    MouseEventKind::ScrollDown => {
-                        self.update(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE))
+            renderer.render(&self.render())?;
+            // Hovered views that stopped rendering are owed a leave message
+            let mut leaves = renderer.app_state().take_hover_leaves::<KeyEvent>();
+            while !leaves.is_empty() {
+                for key in leaves {
+                    self.update(key);
+                }
+                renderer.render(&self.render())?;
+                leaves = renderer.app_state().take_hover_leaves();
+            }
+            let Some(event) = futures::executor::block_on(events.next()) else {
+                break;
+            };
+            match event? {
+                CrosstermEvent::Key(key) if self.is_quit_key(&key) && self.should_quit() => break,
+                CrosstermEvent::Key(key) if self.is_quit_key(&key) => {}
+                CrosstermEvent::Mouse(event) => {
+                    for key in renderer.app_state().dispatch_mouse::<KeyEvent>(&event) {
+                        self.update(key);
                     }
-                    MouseEventKind::ScrollUp => {
-                        self.update(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE))
+                    self.handle_mouse(event);
+                    match event.kind {
+                        MouseEventKind::ScrollDown if options.scroll_as_arrow_keys => {
+                            self.update(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE))
+                        }
+                        MouseEventKind::ScrollUp if options.scroll_as_arrow_keys => {
+                            self.update(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE))
+                        }
+                        _ => {}
                     }
-                    _ => {}
-                },
+                }
                 CrosstermEvent::Resize(width, height) => {
-                    renderer.resize(width, height);
+                    renderer.resize(width, height)?;
                 }
                 CrosstermEvent::Key(event) => {
-                    self.update(event);
+                    match renderer.app_state().dispatch_key::<KeyEvent>(&event) {
+                        KeyResponse::Ignored => self.update(event),
+                        KeyResponse::Consumed => {}
+                        KeyResponse::Message(key) => self.update(key),
+                    }
+                }
+                CrosstermEvent::Paste(text) => {
+                    match renderer.app_state().dispatch_paste::<KeyEvent>(&text) {
+                        KeyResponse::Ignored => self.handle_paste(text),
+                        KeyResponse::Consumed => {}
+                        KeyResponse::Message(key) => self.update(key),
+                    }
                 }
                 _ => {}
             }
         }
 
         if let Some(view) = self.handle_exit() {
-            renderer.render(&view);
+            renderer.render(&view)?;
         }
-        renderer.move_cursor_to_bottom_of_current_view();
+        renderer.move_cursor_to_bottom_of_current_view()?;
+        Ok(())
     }
 }
 
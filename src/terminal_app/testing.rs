@@ -0,0 +1,921 @@
+//! Tools for testing views and apps without a terminal.
+//!
+//! Views are rendered into an in-memory [`Buffer`] with [`render_view`], and apps are
+//! driven with scripted keys and messages by a [`TestHarness`]. Both can be checked
+//! against snapshots of their text, or of their text and styles with
+//! [`styled_snapshot`].
+//!
+//! # Examples
+//! ```
+//! use altar::testing::*;
+//! use altar::*;
+//!
+//! let view = vstack(("Hello", text("There").red()));
+//! let buffer = render_view(view, 6, 2);
+//! assert_buffer(&buffer, ["Hello ", "There "]);
+//! assert_styled_buffer(&buffer, "Hello \nThere \n^^^^^ fg=DarkRed");
+//! ```
+use std::{io, ops::ControlFlow};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use futures::{future::BoxFuture, stream, stream::FuturesUnordered, StreamExt};
+use pretty_assertions::assert_eq;
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+use super::{
+    handle_terminal_event, renderer::fullscreen_renderer::FullScreenRenderer, send_hover_leaves,
+    AsyncTerminalApp, Event,
+};
+use crate::{
+    buffer::{Buffer, Cell, Size, CONTINUATION},
+    Backend, ClearType, Color, Error, RunOptions, Subscription, TerminalEvent, TerminalEvents,
+    View,
+};
+
+const INFALLIBLE: &str = "drawing to a TestBackend does not fail";
+
+/// An in-memory terminal, for running apps and rendering views in tests.
+///
+/// What is drawn is kept in a [`Buffer`] the size of the screen. Events added with
+/// [`TestBackend::push_event`] are read by
+/// [`run_with_backend`](crate::AsyncTerminalAppExt::run_with_backend), after which the
+/// backend runs out of events.
+#[derive(Clone, Debug)]
+pub struct TestBackend {
+    buffer: Buffer,
+    cursor: (u16, u16),
+    events: Vec<TerminalEvent>,
+}
+
+impl TestBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            buffer: Buffer::new(width, height),
+            cursor: (0, 0),
+            events: Vec::new(),
+        }
+    }
+
+    /// The contents of the screen.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Changes the size of the screen, clearing it.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.buffer = Buffer::new(width, height);
+    }
+
+    /// Queues an event to be read from [`Backend::events`].
+    pub fn push_event(&mut self, event: TerminalEvent) -> &mut Self {
+        self.events.push(event);
+        self
+    }
+
+    fn cursor_index(&self) -> usize {
+        let (x, y) = self.cursor;
+        (y as usize * self.buffer.size.width as usize + x as usize).min(self.buffer.cells.len())
+    }
+}
+
+impl Backend for TestBackend {
+    fn draw<'a, I>(&mut self, cells: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in cells {
+            let width = cell.symbol.width().max(1) as u16;
+            for offset in 0..width {
+                let Some(target) = x
+                    .checked_add(offset)
+                    .and_then(|x| self.buffer.try_get_mut(x, y))
+                else {
+                    break;
+                };
+                *target = cell.clone();
+                if offset > 0 {
+                    target.set_symbol(CONTINUATION);
+                }
+            }
+            self.cursor = (x.saturating_add(width), y);
+        }
+        Ok(())
+    }
+
+    fn move_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.cursor = (x, y);
+        Ok(())
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        Ok(self.cursor)
+    }
+
+    fn scroll_up(&mut self, lines: u16) -> io::Result<()> {
+        let cells = &mut self.buffer.cells;
+        let count = (lines as usize * self.buffer.size.width as usize).min(cells.len());
+        cells.drain(..count);
+        cells.resize(cells.len() + count, Cell::default());
+        Ok(())
+    }
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let start = match clear_type {
+            ClearType::All => 0,
+            ClearType::FromCursorDown => self.cursor_index(),
+        };
+        self.buffer.cells[start..]
+            .iter_mut()
+            .for_each(|cell| cell.reset());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.buffer.size)
+    }
+
+    fn events(&mut self) -> TerminalEvents {
+        stream::iter(std::mem::take(&mut self.events).into_iter().map(Ok)).boxed()
+    }
+}
+
+/// Drives an [`AsyncTerminalApp`] with scripted input, rendering it into a
+/// [`TestBackend`] after every event, as [`run`](crate::AsyncTerminalAppExt::run) would.
+///
+/// Keys go through the same quit key, focus and mouse handling as in `run`. Messages
+/// the app sends itself are handled straight after the event that caused them. Use
+/// [`TestHarness::next_message`] to run the futures from the app's commands, or to wait
+/// for messages sent from spawned tasks.
+///
+/// # Examples
+/// ```
+/// use altar::testing::*;
+/// use altar::*;
+/// use tokio::sync::mpsc;
+///
+/// struct Counter(u32);
+///
+/// impl AsyncTerminalApp for Counter {
+///     type Message = ();
+///     type Output = u32;
+///
+///     fn render(&self) -> impl View {
+///         text(format!("Count: {}", self.0))
+///     }
+///
+///     fn update(&mut self, event: Event<()>, _: &mpsc::UnboundedSender<()>) -> Command<(), u32> {
+///         match event {
+///             Event::Key(key) if key.code == KeyCode::Enter => return Command::exit(self.0),
+///             Event::Key(_) => self.0 += 1,
+///             _ => {}
+///         }
+///         Command::none()
+///     }
+/// }
+///
+/// let mut harness = TestHarness::new(Counter(0), 10, 1);
+/// harness.press(KeyCode::Up).press(KeyCode::Up);
+/// harness.assert_snapshot(["Count: 2  "]);
+///
+/// harness.press(KeyCode::Enter);
+/// assert_eq!(harness.into_result().unwrap().unwrap(), 2);
+/// ```
+pub struct TestHarness<A: AsyncTerminalApp> {
+    app: A,
+    options: RunOptions,
+    renderer: FullScreenRenderer<TestBackend>,
+    sender: mpsc::UnboundedSender<A::Message>,
+    receiver: mpsc::UnboundedReceiver<A::Message>,
+    /// The futures started by the app's commands, run by [`TestHarness::next_message`].
+    tasks: FuturesUnordered<BoxFuture<'static, A::Message>>,
+    result: Option<Result<A::Output, Error>>,
+}
+
+impl<A: AsyncTerminalApp> TestHarness<A> {
+    /// Initializes `app` and renders it into a buffer of the given size.
+    pub fn new(app: A, width: u16, height: u16) -> Self {
+        Self::with_options(app, width, height, RunOptions::new())
+    }
+
+    /// Like [`TestHarness::new`], handling events as `run` would with `options`, such as
+    /// [`RunOptions::scroll_as_arrow_keys`]. Options that set up the terminal are ignored.
+    pub fn with_options(mut app: A, width: u16, height: u16, options: RunOptions) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut tasks = FuturesUnordered::new();
+        let flow = app.init(&sender).start(&mut tasks);
+        let mut harness = Self {
+            app,
+            options,
+            renderer: FullScreenRenderer::new(TestBackend::new(width, height)).expect(INFALLIBLE),
+            sender,
+            receiver,
+            tasks,
+            result: None,
+        };
+        harness.finish_event(flow);
+        harness
+    }
+
+    /// Sends a key press. Events are ignored once the app has exited.
+    pub fn key(&mut self, key: KeyEvent) -> &mut Self {
+        self.event(TerminalEvent::Key(key))
+    }
+
+    /// Presses a key without modifiers.
+    pub fn press(&mut self, code: KeyCode) -> &mut Self {
+        self.key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    /// Types each character of `text` as a separate key press.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            self.press(KeyCode::Char(c));
+        }
+        self
+    }
+
+    /// Pastes `text` at once, as a terminal with bracketed paste would.
+    pub fn paste(&mut self, text: &str) -> &mut Self {
+        self.event(TerminalEvent::Paste(text.to_string()))
+    }
+
+    /// Sends a mouse event, such as a click on a view with
+    /// [`on_click`](crate::ViewExtensions::on_click).
+    pub fn mouse(&mut self, event: MouseEvent) -> &mut Self {
+        self.event(TerminalEvent::Mouse(event))
+    }
+
+    /// Resizes the buffer the app renders into, then sends the app [`Event::Resize`].
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.renderer.backend_mut().resize(width, height);
+        self.event(TerminalEvent::Resize(width, height))
+    }
+
+    /// Sends [`Event::FocusGained`] or [`Event::FocusLost`], as if the terminal's window
+    /// gained or lost focus.
+    pub fn focus(&mut self, focused: bool) -> &mut Self {
+        match focused {
+            true => self.event(TerminalEvent::FocusGained),
+            false => self.event(TerminalEvent::FocusLost),
+        }
+    }
+
+    /// Sends the app a message, as if from a background task.
+    pub fn message(&mut self, message: A::Message) -> &mut Self {
+        if self.result.is_none() {
+            let flow = self
+                .app
+                .update(Event::Message(message), &self.sender)
+                .start(&mut self.tasks);
+            self.finish_event(flow);
+        }
+        self
+    }
+
+    /// Ticks each of the app's current [`subscriptions`](AsyncTerminalApp::subscriptions)
+    /// once, without waiting for their intervals.
+    pub fn tick(&mut self) -> &mut Self {
+        let mut intervals = self
+            .app
+            .subscriptions()
+            .iter()
+            .map(Subscription::interval)
+            .collect::<Vec<_>>();
+        intervals.sort();
+        intervals.dedup();
+        for interval in intervals {
+            if self.result.is_some() {
+                break;
+            }
+            let flow = self
+                .app
+                .update(Event::Tick(interval), &self.sender)
+                .start(&mut self.tasks);
+            self.finish_event(flow);
+        }
+        self
+    }
+
+    /// Runs the futures from the app's commands until one of them finishes, or waits for
+    /// a message the app sends itself, and handles the message.
+    pub async fn next_message(&mut self) -> &mut Self {
+        let message = tokio::select! {
+            Some(message) = self.tasks.next() => Some(message),
+            message = self.receiver.recv() => message,
+        };
+        if let Some(message) = message {
+            self.message(message);
+        }
+        self
+    }
+
+    /// The number of futures from the app's commands that have not finished yet.
+    pub fn pending_tasks(&self) -> usize {
+        self.tasks.len()
+    }
+
+    fn event(&mut self, event: TerminalEvent) -> &mut Self {
+        if self.result.is_none() {
+            let flow = handle_terminal_event(
+                &mut self.app,
+                &self.options,
+                event,
+                &self.sender,
+                &mut self.tasks,
+                &mut self.renderer,
+            );
+            self.finish_event(flow);
+        }
+        self
+    }
+
+    /// Handles the messages sent while handling an event, then renders.
+    fn finish_event(&mut self, mut flow: ControlFlow<Result<A::Output, Error>>) {
+        while flow.is_continue() {
+            let Ok(message) = self.receiver.try_recv() else {
+                break;
+            };
+            flow = self
+                .app
+                .update(Event::Message(message), &self.sender)
+                .start(&mut self.tasks);
+        }
+        match flow {
+            ControlFlow::Continue(()) => {
+                self.renderer.render(&self.app.render()).expect(INFALLIBLE);
+                send_hover_leaves(&mut self.renderer, &self.sender);
+                if !self.receiver.is_empty() {
+                    self.finish_event(ControlFlow::Continue(()));
+                }
+            }
+            ControlFlow::Break(result) => {
+                // Cancel the app's commands, as the run loop would
+                self.tasks.clear();
+                if let Some(view) = self.app.handle_exit() {
+                    self.renderer.render(&view).expect(INFALLIBLE);
+                }
+                self.result = Some(result);
+            }
+        }
+    }
+
+    pub fn app(&self) -> &A {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut A {
+        &mut self.app
+    }
+
+    /// The most recently rendered frame. After the app exits, this is the view from
+    /// [`handle_exit`](AsyncTerminalApp::handle_exit), if there is one.
+    pub fn buffer(&self) -> &Buffer {
+        self.renderer.backend().buffer()
+    }
+
+    /// Returns the app's result once it has exited.
+    pub fn result(&self) -> Option<&Result<A::Output, Error>> {
+        self.result.as_ref()
+    }
+
+    /// Returns the app's result, or `None` if it is still running.
+    pub fn into_result(self) -> Option<Result<A::Output, Error>> {
+        self.result
+    }
+
+    /// Asserts that the current frame's text matches `expected`, line by line.
+    #[track_caller]
+    pub fn assert_snapshot<'a>(&self, expected: impl IntoIterator<Item = &'a str>) {
+        assert_buffer(self.buffer(), expected);
+    }
+
+    /// Asserts that the current frame's text and styles match a [`styled_snapshot`].
+    #[track_caller]
+    pub fn assert_styled_snapshot(&self, expected: &str) {
+        assert_styled_buffer(self.buffer(), expected);
+    }
+}
+
+/// Renders a view into a new buffer of the given size.
+pub fn render_view(view: impl View, width: u16, height: u16) -> Buffer {
+    let mut renderer = FullScreenRenderer::new(TestBackend::new(width, height)).expect(INFALLIBLE);
+    renderer.render(&view).expect(INFALLIBLE);
+    renderer.backend().buffer().clone()
+}
+
+/// Asserts that the buffer's text matches `expected`, line by line.
+#[track_caller]
+pub fn assert_buffer<'a>(buffer: &Buffer, expected: impl IntoIterator<Item = &'a str>) {
+    let expected = expected.into_iter().collect::<Vec<_>>().join("\n");
+    assert_eq!(buffer.as_plain_str(), expected);
+}
+
+/// Asserts that the buffer's text and styles match a [`styled_snapshot`].
+#[track_caller]
+pub fn assert_styled_buffer(buffer: &Buffer, expected: &str) {
+    assert_eq!(styled_snapshot(buffer), expected);
+}
+
+/// Prints the buffer's text, with the styled runs of each line marked underneath it.
+///
+/// A run of cells with the same colors and modifiers is underlined with `^` and
+/// followed by its style, such as `fg=Red bg=Blue bold`. Unstyled cells are not marked.
+///
+/// ```text
+/// error: not found
+/// ^^^^^ fg=DarkRed bold
+/// ```
+pub fn styled_snapshot(buffer: &Buffer) -> String {
+    let width = buffer.size.width as usize;
+    let mut lines = Vec::new();
+    if width == 0 {
+        return String::new();
+    }
+    for row in buffer.cells.chunks(width) {
+        lines.push(row.iter().map(|cell| &cell.symbol[..]).collect::<String>());
+
+        let mut start = 0;
+        while start < row.len() {
+            let style = describe_style(&row[start]);
+            let len = row[start..]
+                .iter()
+                .take_while(|cell| describe_style(cell) == style)
+                .count();
+            if let Some(style) = style {
+                lines.push(format!(
+                    "{}{} {}",
+                    " ".repeat(start),
+                    "^".repeat(len),
+                    style
+                ));
+            }
+            start += len;
+        }
+    }
+    lines.join("\n")
+}
+
+fn describe_style(cell: &Cell) -> Option<String> {
+    let mut parts = Vec::new();
+    if cell.fg != Color::Reset {
+        parts.push(format!("fg={:?}", cell.fg));
+    }
+    if cell.bg != Color::Reset {
+        parts.push(format!("bg={:?}", cell.bg));
+    }
+    parts.extend(
+        cell.modifier
+            .iter_names()
+            .map(|(name, _)| name.to_lowercase()),
+    );
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use crossterm::event::{MouseButton, MouseEventKind};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Default)]
+    struct Form {
+        name: String,
+        submitted: bool,
+    }
+
+    enum Message {
+        NameChanged(String),
+        Submit,
+    }
+
+    impl AsyncTerminalApp for Form {
+        type Message = Message;
+        type Output = String;
+
+        fn render(&self) -> impl View {
+            vstack((
+                text_field(&self.name, Message::NameChanged).on_submit(|_| Message::Submit),
+                text(format!("Hello, {}", self.name)).bold(),
+            ))
+        }
+
+        fn update(
+            &mut self,
+            event: Event<Message>,
+            sender: &mpsc::UnboundedSender<Message>,
+        ) -> Command<Message, String> {
+            match event {
+                Event::Message(Message::NameChanged(name)) => self.name = name,
+                // Round trip through the channel, to check that sent messages are handled
+                Event::Message(Message::Submit) if !self.submitted => {
+                    self.submitted = true;
+                    sender.send(Message::Submit).unwrap();
+                }
+                Event::Message(Message::Submit) => return Command::exit(self.name.clone()),
+                _ => {}
+            }
+            Command::none()
+        }
+
+        fn handle_exit(&self) -> Option<impl View> {
+            Some(text("Bye"))
+        }
+    }
+
+    /// Loads a name in the background when started.
+    struct Greeter(Option<String>);
+
+    impl AsyncTerminalApp for Greeter {
+        type Message = String;
+        type Output = ();
+
+        fn render(&self) -> impl View {
+            text(format!("Hi {}", self.0.as_deref().unwrap_or("...")))
+        }
+
+        fn init(&mut self, _: &mpsc::UnboundedSender<String>) -> Command<String> {
+            Command::perform(async { "Ferris".to_string() })
+        }
+
+        fn update(
+            &mut self,
+            event: Event<String>,
+            _: &mpsc::UnboundedSender<String>,
+        ) -> Command<String> {
+            if let Event::Message(name) | Event::Paste(name) = event {
+                self.0 = Some(name);
+            }
+            Command::none()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_harness_runs_commands() {
+        let mut harness = TestHarness::new(Greeter(None), 9, 1);
+        harness.assert_snapshot(["Hi ...   "]);
+        assert_eq!(harness.pending_tasks(), 1);
+
+        harness.next_message().await;
+        harness.assert_snapshot(["Hi Ferris"]);
+        assert_eq!(harness.pending_tasks(), 0);
+    }
+
+    #[test]
+    fn test_harness_types_into_focused_field() {
+        let mut harness = TestHarness::new(Form::default(), 14, 2);
+        harness.type_text("Ferris");
+        harness.assert_snapshot(["Ferris        ", "Hello, Ferris "]);
+        assert!(harness.result().is_none());
+
+        harness.press(KeyCode::Enter);
+        harness.assert_snapshot(["Bye           ", "              "]);
+        assert_eq!(harness.into_result().unwrap().unwrap(), "Ferris");
+    }
+
+    #[test]
+    fn test_ctrl_d_deletes_in_focused_field() {
+        let mut harness = TestHarness::new(Form::default(), 14, 2);
+        harness
+            .type_text("Ferris")
+            .press(KeyCode::Home)
+            .key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL));
+        harness.assert_snapshot(["erris         ", "Hello, erris  "]);
+        assert!(harness.result().is_none());
+    }
+
+    #[test]
+    fn test_paste_goes_to_focused_field_at_once() {
+        let mut harness = TestHarness::new(Form::default(), 14, 2);
+        harness.paste("Fer\nris");
+        harness.assert_snapshot(["Fer ris       ", "Hello, Fer ris"]);
+        assert!(harness.result().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_paste_without_focused_view_reaches_update() {
+        let mut harness = TestHarness::new(Greeter(None), 9, 1);
+        harness.paste("Ferris");
+        harness.assert_snapshot(["Hi Ferris"]);
+    }
+
+    #[test]
+    fn test_harness_quit_key_interrupts() {
+        let mut harness = TestHarness::new(Form::default(), 12, 2);
+        harness.key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        harness.type_text("ignored");
+        assert!(matches!(harness.result(), Some(Err(Error::Interrupted))));
+    }
+
+    /// A list whose last row is removed by pressing a key, tracking the hovered row.
+    struct Rows {
+        rows: Vec<&'static str>,
+        hovered: Option<&'static str>,
+    }
+
+    impl AsyncTerminalApp for Rows {
+        type Message = (&'static str, bool);
+        type Output = ();
+
+        fn render(&self) -> impl View {
+            vstack(
+                self.rows
+                    .iter()
+                    .map(|&row| text(row).on_hover(move |hovered| (row, hovered)).id(row))
+                    .collect::<Vec<_>>(),
+            )
+        }
+
+        fn update(
+            &mut self,
+            event: Event<(&'static str, bool)>,
+            _: &mpsc::UnboundedSender<(&'static str, bool)>,
+        ) -> Command<(&'static str, bool)> {
+            match event {
+                Event::Message((row, true)) => self.hovered = Some(row),
+                Event::Message((row, false)) if self.hovered == Some(row) => self.hovered = None,
+                Event::Key(_) => {
+                    self.rows.pop();
+                }
+                _ => {}
+            }
+            Command::none()
+        }
+    }
+
+    #[test]
+    fn test_removed_row_is_no_longer_hovered() {
+        let rows = Rows {
+            rows: vec!["a", "b"],
+            hovered: None,
+        };
+        let mut harness = TestHarness::new(rows, 4, 2);
+        harness.mouse(MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 0,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        });
+        assert_eq!(harness.app().hovered, Some("b"));
+
+        harness.press(KeyCode::Char('x'));
+        assert_eq!(harness.app().hovered, None);
+    }
+
+    #[test]
+    fn test_harness_resize() {
+        let mut harness = TestHarness::new(Form::default(), 12, 2);
+        harness.type_text("Ferris").resize(8, 3);
+        harness.assert_snapshot(["Ferris  ", "Hello,  ", "Ferris  "]);
+    }
+
+    /// Shows the terminal's size and focus, as reported by events.
+    struct Window {
+        size: Size,
+        focused: bool,
+    }
+
+    impl AsyncTerminalApp for Window {
+        type Message = ();
+        type Output = ();
+
+        fn render(&self) -> impl View {
+            let focus = if self.focused { "on" } else { "off" };
+            text(format!("{}x{} {focus}", self.size.width, self.size.height))
+        }
+
+        fn update(&mut self, event: Event<()>, _: &mpsc::UnboundedSender<()>) -> Command<()> {
+            match event {
+                Event::Resize(size) => self.size = size,
+                Event::FocusGained => self.focused = true,
+                Event::FocusLost => self.focused = false,
+                _ => {}
+            }
+            Command::none()
+        }
+    }
+
+    #[test]
+    fn test_resize_and_focus_reach_update() {
+        let mut harness = TestHarness::new(
+            Window {
+                size: Size::new(8, 1),
+                focused: false,
+            },
+            8,
+            1,
+        );
+        harness.resize(9, 1).focus(true);
+        harness.assert_snapshot(["9x1 on   "]);
+
+        harness.focus(false);
+        harness.assert_snapshot(["9x1 off  "]);
+    }
+
+    /// Logs the mouse events and keys it receives.
+    #[derive(Default)]
+    struct Pointer(Vec<String>);
+
+    impl AsyncTerminalApp for Pointer {
+        type Message = ();
+        type Output = ();
+
+        fn render(&self) -> impl View {
+            text(self.0.join(", "))
+        }
+
+        fn update(&mut self, event: Event<()>, _: &mpsc::UnboundedSender<()>) -> Command<()> {
+            match event {
+                Event::Mouse(event) => {
+                    let shift = if event.modifiers.contains(KeyModifiers::SHIFT) {
+                        " shift"
+                    } else {
+                        ""
+                    };
+                    self.0.push(format!(
+                        "{:?} {},{}{shift}",
+                        event.kind, event.column, event.row
+                    ));
+                }
+                Event::Key(key) => self.0.push(format!("{:?}", key.code)),
+                _ => {}
+            }
+            Command::none()
+        }
+    }
+
+    fn mouse(kind: MouseEventKind, modifiers: KeyModifiers) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column: 2,
+            row: 1,
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn test_mouse_events_reach_update() {
+        let mut harness = TestHarness::new(Pointer::default(), 10, 2);
+        harness
+            .mouse(mouse(
+                MouseEventKind::Down(MouseButton::Left),
+                KeyModifiers::SHIFT,
+            ))
+            .mouse(mouse(MouseEventKind::ScrollUp, KeyModifiers::NONE));
+        assert_eq!(
+            harness.app().0,
+            ["Down(Left) 2,1 shift", "ScrollUp 2,1", "Up"]
+        );
+    }
+
+    #[test]
+    fn test_scroll_as_arrow_keys_can_be_turned_off() {
+        let options = RunOptions::new().scroll_as_arrow_keys(false);
+        let mut harness = TestHarness::with_options(Pointer::default(), 10, 2, options);
+        harness.mouse(mouse(MouseEventKind::ScrollDown, KeyModifiers::NONE));
+        assert_eq!(harness.app().0, ["ScrollDown 2,1"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_test_backend() {
+        let mut backend = TestBackend::new(14, 2);
+        for c in "Ferris".chars() {
+            backend.push_event(TerminalEvent::Key(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )));
+        }
+        backend.push_event(TerminalEvent::Key(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+
+        let mut form = Form::default();
+        let options = RunOptions::new().full_screen(true);
+        let result = form.run_with_backend(options, backend).await;
+        assert_eq!(result.unwrap(), "Ferris");
+    }
+
+    /// Records its keys, which views also send it when a row is clicked or hovered.
+    struct SyncRows {
+        rows: Vec<char>,
+        keys: Vec<KeyCode>,
+    }
+
+    impl SyncTerminalApp for SyncRows {
+        fn render(&self) -> impl View {
+            let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+            vstack(
+                self.rows
+                    .iter()
+                    .map(|&row| {
+                        text(row.to_string())
+                            .on_click(move || key(row))
+                            .on_hover(move |hovered| key(if hovered { '+' } else { '-' }))
+                            .id(row)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        }
+
+        fn update(&mut self, event: KeyEvent) {
+            if event.code == KeyCode::Char('x') {
+                self.rows.pop();
+            }
+            self.keys.push(event.code);
+        }
+    }
+
+    #[test]
+    fn test_sync_app_receives_mouse_messages() {
+        let at = |kind, row| MouseEvent {
+            kind,
+            column: 0,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let mut backend = TestBackend::new(4, 2);
+        backend
+            .push_event(TerminalEvent::Mouse(at(MouseEventKind::Moved, 1)))
+            .push_event(TerminalEvent::Mouse(at(
+                MouseEventKind::Down(MouseButton::Left),
+                1,
+            )))
+            .push_event(TerminalEvent::Key(KeyEvent::new(
+                KeyCode::Char('x'),
+                KeyModifiers::NONE,
+            )));
+
+        let mut app = SyncRows {
+            rows: vec!['a', 'b'],
+            keys: Vec::new(),
+        };
+        let options = RunOptions::new().full_screen(true);
+        app.run_with_backend(options, backend).unwrap();
+        let keys = ['+', 'b', 'x', '-'].map(KeyCode::Char);
+        assert_eq!(app.keys, keys);
+    }
+
+    /// A sync app with a text field, whose edits arrive as `c` keys.
+    #[derive(Default)]
+    struct SyncField {
+        keys: Vec<KeyCode>,
+        pastes: Vec<String>,
+    }
+
+    impl SyncTerminalApp for SyncField {
+        fn render(&self) -> impl View {
+            text_field("", |_| {
+                KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE)
+            })
+        }
+
+        fn update(&mut self, event: KeyEvent) {
+            self.keys.push(event.code);
+        }
+
+        fn handle_paste(&mut self, text: String) {
+            self.pastes.push(text);
+        }
+    }
+
+    #[test]
+    fn test_sync_app_paste_goes_to_focused_field() {
+        let mut backend = TestBackend::new(8, 1);
+        backend.push_event(TerminalEvent::Paste("Ferris".to_string()));
+
+        let mut app = SyncField::default();
+        let options = RunOptions::new().full_screen(true);
+        app.run_with_backend(options, backend).unwrap();
+        assert_eq!(app.keys, [KeyCode::Char('c')]);
+        assert!(app.pastes.is_empty());
+    }
+
+    #[test]
+    fn test_backend_keeps_wide_characters_whole() {
+        let buffer = render_view(text("日本!").red(), 6, 1);
+        assert_styled_buffer(&buffer, "日本! \n^^^^^ fg=DarkRed");
+        assert!(buffer.get(1, 0).unwrap().is_continuation());
+    }
+
+    #[test]
+    fn test_styled_snapshot() {
+        let view = vstack((
+            hstack((text("error").red().bold(), text("not found"))),
+            text("ok").background(Color::Blue),
+        ));
+        let expected = [
+            "error not found",
+            "^^^^^ fg=DarkRed bold",
+            "ok             ",
+            "^^ bg=Blue",
+        ]
+        .join("\n");
+        assert_styled_buffer(&render_view(view, 15, 2), &expected);
+    }
+}
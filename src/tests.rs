@@ -2,6 +2,28 @@ use self::view::View;
 
 use super::*;
 use pretty_assertions::assert_eq;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Held by tests that replace the process-wide panic hook or panic on purpose, so that
+/// they don't see each other's hooks when the tests run in parallel.
+pub(crate) fn lock_panic_hook() -> MutexGuard<'static, ()> {
+    static PANIC_HOOK: Mutex<()> = Mutex::new(());
+    PANIC_HOOK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Renders `view` into a new buffer as one frame with `state`, as the renderers do, so that
+/// what views keep in it between frames, such as scroll offsets and focus, carries over
+/// from one call to the next.
+pub(crate) fn render_with_state(
+    view: &impl View,
+    state: &mut AppState,
+    width: u16,
+    height: u16,
+) -> Buffer {
+    let mut buffer = Buffer::new(width, height);
+    state.render_frame(view, Rect::new(0, 0, width, height), &mut buffer);
+    buffer
+}
 
 pub(crate) fn assert_rendered_view(
     view: impl View,
@@ -9,17 +31,7 @@ pub(crate) fn assert_rendered_view(
     buffer_width: u16,
     buffer_height: u16,
 ) {
-    let mut buffer = Buffer::new(buffer_width, buffer_height);
-    let render_context = Context::new(Rect {
-        point: Point::zero(),
-        size: buffer.size.clone(),
-    });
-    view.render(
-        &mut ViewId::empty(),
-        render_context,
-        &mut AppState::new(),
-        &mut buffer,
-    );
+    let buffer = render_with_state(&view, &mut AppState::new(), buffer_width, buffer_height);
     let result: String = buffer.as_plain_str();
     assert_eq!(result, expected.join("\n"));
 }
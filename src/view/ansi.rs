@@ -0,0 +1,356 @@
+use unicode_width::UnicodeWidthChar;
+
+use super::*;
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+const TAB_WIDTH: usize = 8;
+
+/// The style in effect while parsing, changed by each SGR sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    modifier: Modifier,
+}
+
+impl Style {
+    const RESET: Style = Style {
+        fg: None,
+        bg: None,
+        modifier: Modifier::empty(),
+    };
+
+    fn apply(&mut self, params: &str) {
+        let mut params = params.split(';').map(|param| {
+            param
+                .split(':')
+                .map(|sub| sub.parse::<u16>().ok())
+                .collect::<Vec<_>>()
+        });
+        while let Some(param) = params.next() {
+            let code = param[0].unwrap_or(0);
+            match code {
+                0 => *self = Style::RESET,
+                1 => self.modifier |= Modifier::BOLD,
+                2 => self.modifier |= Modifier::DIM,
+                3 => self.modifier |= Modifier::ITALIC,
+                4 | 21 => self.modifier |= Modifier::UNDERLINE,
+                5 | 6 => self.modifier |= Modifier::BLINK,
+                7 => self.modifier |= Modifier::INVERSE,
+                8 => self.modifier |= Modifier::HIDDEN,
+                9 => self.modifier |= Modifier::STRIKETHROUGH,
+                22 => self.modifier -= Modifier::BOLD | Modifier::DIM,
+                23 => self.modifier -= Modifier::ITALIC,
+                24 => self.modifier -= Modifier::UNDERLINE,
+                25 => self.modifier -= Modifier::BLINK,
+                27 => self.modifier -= Modifier::INVERSE,
+                28 => self.modifier -= Modifier::HIDDEN,
+                29 => self.modifier -= Modifier::STRIKETHROUGH,
+                30..=37 => self.fg = Some(basic_color(code - 30)),
+                90..=97 => self.fg = Some(bright_color(code - 90)),
+                40..=47 => self.bg = Some(basic_color(code - 40)),
+                100..=107 => self.bg = Some(bright_color(code - 100)),
+                39 => self.fg = None,
+                49 => self.bg = None,
+                38 | 48 => {
+                    // Colon-separated forms carry their arguments in the same parameter,
+                    // while the semicolon forms take them from the ones that follow.
+                    let color = if param.len() > 1 {
+                        extended_color(&param[1..])
+                    } else {
+                        let kind = params.next().and_then(|param| param[0]);
+                        let arguments = match kind {
+                            Some(5) => params.next().map(|param| vec![Some(5), param[0]]),
+                            Some(2) => {
+                                let rgb = params.by_ref().take(3).map(|param| param[0]);
+                                Some([Some(2)].into_iter().chain(rgb).collect())
+                            }
+                            _ => None,
+                        };
+                        arguments.and_then(|arguments| extended_color(&arguments))
+                    };
+                    match (code, color) {
+                        (38, Some(color)) => self.fg = Some(color),
+                        (48, Some(color)) => self.bg = Some(color),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Maps the eight standard colors, which terminals show in their normal intensity.
+fn basic_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::DarkRed,
+        2 => Color::DarkGreen,
+        3 => Color::DarkYellow,
+        4 => Color::DarkBlue,
+        5 => Color::DarkMagenta,
+        6 => Color::DarkCyan,
+        _ => Color::Grey,
+    }
+}
+
+fn bright_color(index: u16) -> Color {
+    match index {
+        0 => Color::DarkGrey,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+/// Parses the arguments of a 38 or 48 parameter, either `5;n` or `2;r;g;b`.
+/// The colon form of truecolor may include an empty color space id before the channels.
+fn extended_color(arguments: &[Option<u16>]) -> Option<Color> {
+    let byte = |value: &u16| u8::try_from(*value).ok();
+    match arguments {
+        [Some(5), Some(index)] => Some(Color::AnsiValue(byte(index)?)),
+        [Some(2), _, Some(r), Some(g), Some(b)] | [Some(2), Some(r), Some(g), Some(b)] => {
+            Some(Color::Rgb {
+                r: byte(r)?,
+                g: byte(g)?,
+                b: byte(b)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Splits text containing ANSI escape sequences into styled spans.
+///
+/// SGR sequences become colors and modifiers. Every other escape sequence and
+/// control character is dropped, except for `\n`, tabs, which are expanded to the
+/// next multiple of eight columns, and `\r`. Text written after a `\r` replaces the
+/// line, as progress bars expect.
+pub(crate) fn parse_ansi(input: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut style = Style::RESET;
+    let mut text = String::new();
+    let mut column = 0;
+    // Set by `\r`, so the line is only discarded once something replaces it
+    let mut returned = false;
+
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ESC => {
+                let next_style = match chars.next() {
+                    // Control Sequence Introducer: parameters, then a final byte in `@..=~`.
+                    Some('[') => {
+                        let mut params = String::new();
+                        let mut last = None;
+                        for c in chars.by_ref() {
+                            if ('@'..='~').contains(&c) {
+                                last = Some(c);
+                                break;
+                            }
+                            params.push(c);
+                        }
+                        let mut next_style = style;
+                        if last == Some('m') {
+                            next_style.apply(&params);
+                        }
+                        next_style
+                    }
+                    // Operating System Command, such as a title or hyperlink, ended by BEL or ST.
+                    Some(']') => {
+                        while let Some(c) = chars.next() {
+                            if c == BEL || c == ESC && chars.next_if_eq(&'\\').is_some() {
+                                break;
+                            }
+                        }
+                        style
+                    }
+                    // Other escapes are intermediate bytes in ` ..=/`, then one final byte.
+                    Some(c) if (' '..='/').contains(&c) => {
+                        while chars.next_if(|c| (' '..='/').contains(c)).is_some() {}
+                        chars.next();
+                        style
+                    }
+                    _ => style,
+                };
+                if next_style != style && !text.is_empty() {
+                    spans.push(span_with_style(std::mem::take(&mut text), style));
+                }
+                style = next_style;
+            }
+            '\n' => {
+                text.push('\n');
+                column = 0;
+                returned = false;
+            }
+            '\r' => {
+                column = 0;
+                returned = true;
+            }
+            c if returned && !c.is_control() => {
+                discard_line(&mut spans, &mut text);
+                returned = false;
+                text.push(c);
+                column += c.width().unwrap_or(0);
+            }
+            '\t' => {
+                let spaces = TAB_WIDTH - column % TAB_WIDTH;
+                text.extend(std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+            c if c.is_control() => {}
+            c => {
+                text.push(c);
+                column += c.width().unwrap_or(0);
+            }
+        }
+    }
+    if !text.is_empty() {
+        spans.push(span_with_style(text, style));
+    }
+    spans
+}
+
+/// Removes the text after the last `\n`, from the pending text and then from the spans.
+fn discard_line(spans: &mut Vec<Span>, text: &mut String) {
+    if let Some(index) = text.rfind('\n') {
+        text.truncate(index + 1);
+        return;
+    }
+    text.clear();
+    while let Some(span) = spans.last_mut() {
+        if let Some(index) = span.text.rfind('\n') {
+            span.text.truncate(index + 1);
+            return;
+        }
+        spans.pop();
+    }
+}
+
+fn span_with_style(text: String, style: Style) -> Span {
+    Span {
+        text: text.into(),
+        fg: style.fg,
+        bg: style.bg,
+        modifier: style.modifier,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn styles(input: &str) -> Vec<(String, Option<Color>, Option<Color>, Modifier)> {
+        parse_ansi(input)
+            .into_iter()
+            .map(|span| (span.text.to_string(), span.fg, span.bg, span.modifier))
+            .collect()
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(
+            styles("hello"),
+            [("hello".into(), None, None, Modifier::empty())]
+        );
+        assert!(parse_ansi("").is_empty());
+    }
+
+    #[test]
+    fn test_basic_colors_and_reset() {
+        let input = "\x1b[1;31merror\x1b[0m: \x1b[92mok\x1b[39m!";
+        assert_eq!(
+            styles(input),
+            [
+                ("error".into(), Some(Color::DarkRed), None, Modifier::BOLD),
+                (": ".into(), None, None, Modifier::empty()),
+                ("ok".into(), Some(Color::Green), None, Modifier::empty()),
+                ("!".into(), None, None, Modifier::empty()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extended_colors() {
+        let rgb = Color::Rgb { r: 1, g: 2, b: 3 };
+        assert_eq!(
+            styles("\x1b[38;5;208;48;2;1;2;3ma\x1b[38:2::1:2:3;49mb"),
+            [
+                (
+                    "a".into(),
+                    Some(Color::AnsiValue(208)),
+                    Some(rgb),
+                    Modifier::empty()
+                ),
+                ("b".into(), Some(rgb), None, Modifier::empty()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modifiers_are_removed() {
+        assert_eq!(
+            styles("\x1b[1;3;4mA\x1b[22;24mB\x1b[mC"),
+            [
+                (
+                    "A".into(),
+                    None,
+                    None,
+                    Modifier::BOLD | Modifier::ITALIC | Modifier::UNDERLINE
+                ),
+                ("B".into(), None, None, Modifier::ITALIC),
+                ("C".into(), None, None, Modifier::empty()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_other_sequences_are_discarded() {
+        let input =
+            "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07\n\x1b[2K\x1b[1G50%\rdone\r\x1b(B";
+        assert_eq!(
+            styles(input),
+            [("link\ndone".into(), None, None, Modifier::empty())]
+        );
+    }
+
+    #[test]
+    fn test_carriage_return_replaces_the_line() {
+        let input = "ok\n\x1b[1m 10%\x1b[0m fetching\r\x1b[1m100%\x1b[0m done\r\nnext";
+        assert_eq!(
+            styles(input),
+            [
+                ("ok\n".into(), None, None, Modifier::empty()),
+                ("100%".into(), None, None, Modifier::BOLD),
+                (" done\nnext".into(), None, None, Modifier::empty()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tabs_are_expanded() {
+        assert_eq!(parse_ansi("ab\tc\n\td")[0].text, "ab      c\n        d");
+    }
+
+    #[test]
+    fn test_ansi_text_renders() {
+        let view = ansi_text("\x1b[32m   Compiling\x1b[0m altar");
+        let mut buffer = Buffer::new(18, 1);
+        view.render(
+            &mut ViewId::empty(),
+            Context::new(Rect::new(0, 0, 18, 1)),
+            &mut AppState::new(),
+            &mut buffer,
+        );
+        assert_eq!(buffer.as_plain_str(), "   Compiling altar");
+        assert_eq!(buffer.get_mut(3, 0).fg, Color::DarkGreen);
+        assert_eq!(buffer.get_mut(13, 0).fg, Color::Reset);
+    }
+}
@@ -49,7 +49,15 @@ impl View for FillColor {
         let size = context.rect.size;
         for y in point.y..point.y + size.height {
             for x in point.x..point.x + size.width {
-                buffer.set_char_at(x, y, ' ', Color::Reset, Some(self.color), Modifier::empty());
+                buffer.set_clipped_char_at(
+                    context.clip,
+                    x,
+                    y,
+                    ' ',
+                    Color::Reset,
+                    Some(self.color),
+                    Modifier::empty(),
+                );
             }
         }
     }
@@ -37,14 +37,9 @@ impl<V> Border<V> {
         self
     }
 
-    fn draw_corner(buffer: &mut Buffer, x: u16, y: u16, symbol: char, color: Color) {
-        buffer
-            .get_mut(x, y)
-            .set_symbol(&symbol.to_string())
-            .set_fg(color);
-    }
-
-    fn draw_borders(&self, buffer: &mut Buffer, rect: Rect) {
+    fn draw_borders(&self, context: &Context, buffer: &mut Buffer) {
+        let rect = context.rect;
+        let clip = context.clip;
         let left = rect.left();
         let top = rect.top();
         let right = rect.right().saturating_sub(1);
@@ -53,26 +48,28 @@ impl<V> Border<V> {
         let components = self.border_style.components();
 
         // Draw corners
-        Self::draw_corner(buffer, left, top, components.top_left, self.border_color);
-        Self::draw_corner(buffer, right, top, components.top_right, self.border_color);
-        Self::draw_corner(
-            buffer,
-            left,
-            bottom_y,
-            components.bottom_left,
-            self.border_color,
-        );
-        Self::draw_corner(
-            buffer,
-            right,
-            bottom_y,
-            components.bottom_right,
-            self.border_color,
-        );
+        let corners = [
+            (left, top, components.top_left),
+            (right, top, components.top_right),
+            (left, bottom_y, components.bottom_left),
+            (right, bottom_y, components.bottom_right),
+        ];
+        for (x, y, symbol) in corners {
+            buffer.set_clipped_char_at(
+                clip,
+                x,
+                y,
+                symbol,
+                self.border_color,
+                None,
+                Modifier::empty(),
+            );
+        }
 
         // Draw horizontal lines
         draw_horizontal_line(
             buffer,
+            clip,
             top,
             left + 1,
             right,
@@ -81,6 +78,7 @@ impl<V> Border<V> {
         );
         draw_horizontal_line(
             buffer,
+            clip,
             bottom_y,
             left + 1,
             right,
@@ -91,6 +89,7 @@ impl<V> Border<V> {
         // Draw vertical lines
         draw_vertical_line(
             buffer,
+            clip,
             left,
             top + 1,
             bottom_y,
@@ -99,6 +98,7 @@ impl<V> Border<V> {
         );
         draw_vertical_line(
             buffer,
+            clip,
             right,
             top + 1,
             bottom_y,
@@ -122,6 +122,7 @@ impl<V> Border<V> {
                     },
                     fg: self.border_color,
                     modifier: Modifier::empty(),
+                    ..context.clone()
                 },
                 &mut AppState::new(),
                 buffer,
@@ -185,6 +186,7 @@ const ROUNDED_BORDER_COMPONENTS: BorderComponents = BorderComponents {
 
 fn draw_horizontal_line(
     buffer: &mut Buffer,
+    clip: Option<Rect>,
     y: u16,
     start_x: u16,
     end_x: u16,
@@ -192,12 +194,13 @@ fn draw_horizontal_line(
     color: Color,
 ) {
     for x in start_x..end_x {
-        buffer.set_char_at(x, y, char, color, None, Modifier::empty());
+        buffer.set_clipped_char_at(clip, x, y, char, color, None, Modifier::empty());
     }
 }
 
 fn draw_vertical_line(
     buffer: &mut Buffer,
+    clip: Option<Rect>,
     x: u16,
     start_y: u16,
     end_y: u16,
@@ -205,7 +208,7 @@ fn draw_vertical_line(
     color: Color,
 ) {
     for y in start_y..end_y {
-        buffer.set_char_at(x, y, char, color, None, Modifier::empty());
+        buffer.set_clipped_char_at(clip, x, y, char, color, None, Modifier::empty());
     }
 }
 
@@ -232,7 +235,7 @@ impl<V: View> View for Border<V> {
         self.child.render(id, inner_context, state, buffer);
 
         // Draw the borders around the border rectangle
-        self.draw_borders(buffer, border_context.rect);
+        self.draw_borders(&border_context, buffer);
     }
 }
 
@@ -326,4 +329,23 @@ mod tests {
         );
         assert_rendered_view(view, expected_output, 15, 3);
     }
+
+    #[test]
+    fn test_border_and_title_are_clipped() {
+        let view = text("Hello").border().title("Title");
+        let mut buffer = Buffer::new(9, 3);
+        let context = Context::new(Rect::new(0, 0, 9, 3)).clip(Rect::new(0, 0, 4, 3));
+        view.render(
+            &mut ViewId::empty(),
+            context,
+            &mut AppState::new(),
+            &mut buffer,
+        );
+        let expected_output = [
+            "┌Tit     ", //
+            "│ He     ", //
+            "└───     ", //
+        ];
+        assert_eq!(buffer.as_plain_str(), expected_output.join("\n"));
+    }
 }
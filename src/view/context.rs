@@ -5,6 +5,8 @@ pub struct Context {
     pub(crate) rect: Rect,
     pub(crate) fg: Color,
     pub(crate) modifier: Modifier,
+    /// The area a view may draw in, when smaller than the buffer.
+    pub(crate) clip: Option<Rect>,
 }
 
 impl Context {
@@ -13,9 +15,16 @@ impl Context {
             rect,
             fg: Color::Reset,
             modifier: Modifier::empty(),
+            clip: None,
         }
     }
 
+    /// Limits drawing to `rect`, within any clip already in effect.
+    pub fn clip(mut self, rect: Rect) -> Self {
+        self.clip = Some(self.clip.map_or(rect, |clip| clip.intersection(rect)));
+        self
+    }
+
     pub fn with_size(mut self, size: Size) -> Self {
         self.rect.size = size;
         self
@@ -32,7 +41,7 @@ impl Context {
     }
 
     pub fn with_modifier(mut self, modifier: Option<Modifier>) -> Self {
-        self.modifier = self.modifier | modifier.unwrap_or_else(Modifier::empty);
+        self.modifier |= modifier.unwrap_or_else(Modifier::empty);
         self
     }
 
@@ -48,6 +57,7 @@ impl Default for Context {
             rect: Rect::new(0, 0, 0, 0),
             fg: Color::Reset,
             modifier: Modifier::empty(),
+            clip: None,
         }
     }
 }
@@ -0,0 +1,372 @@
+use std::sync::Arc;
+
+use super::*;
+
+/// What a view did with a key event that was routed to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyResponse<M> {
+    /// The view did not handle the key, so it is offered to the next view or the app.
+    Ignored,
+    /// The view handled the key itself.
+    Consumed,
+    /// The view handled the key and produced a message for the app.
+    Message(M),
+}
+
+type StateHandler = Arc<dyn Fn(&KeyEvent, &mut AppState) -> bool + Send + Sync>;
+pub(crate) type MessageHandler<M> =
+    Arc<dyn Fn(&KeyEvent, &mut AppState) -> KeyResponse<M> + Send + Sync>;
+/// Handles pasted text for a view, such as a text field inserting it in one edit.
+pub(crate) type PasteHandler<M> = Arc<dyn Fn(&str, &mut AppState) -> KeyResponse<M> + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) enum KeyHandler {
+    /// A handler that only updates view state, such as scrolling a `ScrollView`.
+    State(StateHandler),
+    /// A type-erased `MessageHandler<M>` for the app's message type.
+    Message(Arc<dyn Any + Send + Sync>),
+}
+
+/// The focus ring, rebuilt from the focusable views on every render.
+#[derive(Default)]
+pub struct FocusState {
+    ring: Vec<ViewId>,
+    focused: Option<ViewId>,
+    handlers: Vec<(ViewId, KeyHandler)>,
+    /// Type-erased `PasteHandler<M>`s for the app's message type.
+    paste_handlers: Vec<(ViewId, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl Debug for FocusState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FocusState")
+            .field("ring", &self.ring)
+            .field("focused", &self.focused)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FocusState {
+    pub(crate) fn start_frame(&mut self) {
+        self.ring.clear();
+        self.handlers.clear();
+        self.paste_handlers.clear();
+    }
+
+    /// Focuses the first focusable view when the focused view was not rendered, returning
+    /// true if that changed the focus.
+    pub(crate) fn finish_frame(&mut self) -> bool {
+        let is_rendered = |id: &ViewId| self.ring.contains(id);
+        if self.focused.as_ref().is_some_and(is_rendered) {
+            return false;
+        }
+        let focused = self.ring.first().cloned();
+        let changed = focused != self.focused;
+        self.focused = focused;
+        changed
+    }
+
+    fn step(&mut self, forward: bool) {
+        if self.ring.is_empty() {
+            return;
+        }
+        let len = self.ring.len();
+        let index = match self
+            .focused
+            .as_ref()
+            .and_then(|id| self.ring.iter().position(|x| x == id))
+        {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        };
+        self.focused = Some(self.ring[index].clone());
+    }
+}
+
+impl AppState {
+    /// Returns the id of the focused view, if any.
+    pub(crate) fn focused(&self) -> Option<&ViewId> {
+        self.focus.focused.as_ref()
+    }
+
+    pub(crate) fn is_focused(&self, id: &ViewId) -> bool {
+        self.focused() == Some(id)
+    }
+
+    /// Moves focus to the next focusable view, wrapping around at the end.
+    pub fn focus_next(&mut self) {
+        self.focus.step(true);
+    }
+
+    /// Moves focus to the previous focusable view, wrapping around at the start.
+    pub fn focus_previous(&mut self) {
+        self.focus.step(false);
+    }
+
+    pub(crate) fn register_focusable(&mut self, id: &ViewId) {
+        self.focus.ring.push(id.clone());
+    }
+
+    pub(crate) fn register_key_handler(&mut self, id: &ViewId, handler: KeyHandler) {
+        self.focus.handlers.push((id.clone(), handler));
+    }
+
+    pub(crate) fn register_paste_handler<M: Send + 'static>(
+        &mut self,
+        id: &ViewId,
+        handler: PasteHandler<M>,
+    ) {
+        self.focus
+            .paste_handlers
+            .push((id.clone(), Arc::new(handler)));
+    }
+
+    /// Returns the handlers registered by the focused view, its children and its
+    /// parents, innermost first.
+    fn focused_handlers<H: Clone>(&self, handlers: &[(ViewId, H)]) -> Vec<H> {
+        let Some(focused) = self.focus.focused.as_ref() else {
+            return Vec::new();
+        };
+        let mut handlers = handlers
+            .iter()
+            .filter(|(id, _)| id.starts_with(focused) || focused.starts_with(id))
+            .cloned()
+            .collect::<Vec<_>>();
+        handlers.sort_by_key(|(id, _)| std::cmp::Reverse(id.path.len()));
+        handlers.into_iter().map(|(_, handler)| handler).collect()
+    }
+
+    /// Routes a key event to the focused view.
+    ///
+    /// Key handlers registered by the focused view, its children and its parents are
+    /// tried innermost first. If none of them handle it, Tab and Shift-Tab move the focus.
+    pub fn dispatch_key<M: 'static>(&mut self, key: &KeyEvent) -> KeyResponse<M> {
+        if self.focus.focused.is_none() {
+            return KeyResponse::Ignored;
+        }
+
+        for handler in self.focused_handlers(&self.focus.handlers) {
+            let response = match handler {
+                KeyHandler::State(handler) if handler(key, self) => KeyResponse::Consumed,
+                KeyHandler::State(_) => KeyResponse::Ignored,
+                KeyHandler::Message(handler) => match handler.downcast_ref::<MessageHandler<M>>() {
+                    Some(handler) => handler(key, self),
+                    None => KeyResponse::Ignored,
+                },
+            };
+            if !matches!(response, KeyResponse::Ignored) {
+                return response;
+            }
+        }
+
+        match key.code {
+            KeyCode::Tab => self.focus_next(),
+            KeyCode::BackTab => self.focus_previous(),
+            _ => return KeyResponse::Ignored,
+        }
+        KeyResponse::Consumed
+    }
+
+    /// Routes pasted text to the focused view, trying the same views as
+    /// [`dispatch_key`](AppState::dispatch_key).
+    pub fn dispatch_paste<M: 'static>(&mut self, text: &str) -> KeyResponse<M> {
+        for handler in self.focused_handlers(&self.focus.paste_handlers) {
+            let response = match handler.downcast_ref::<PasteHandler<M>>() {
+                Some(handler) => handler(text, self),
+                None => KeyResponse::Ignored,
+            };
+            if !matches!(response, KeyResponse::Ignored) {
+                return response;
+            }
+        }
+        KeyResponse::Ignored
+    }
+}
+
+/// A view that takes part in Tab/Shift-Tab focus traversal.
+#[derive(Clone, Debug)]
+pub struct Focusable<V> {
+    pub(crate) child: V,
+    pub(crate) focused_fg: Option<Color>,
+    pub(crate) focused_modifier: Option<Modifier>,
+}
+
+impl<V> private::Sealed for Focusable<V> {}
+
+impl<V> Focusable<V> {
+    /// Sets the foreground color of the child while it is focused.
+    pub fn focused_color(mut self, color: Color) -> Self {
+        self.focused_fg = Some(color);
+        self
+    }
+
+    /// Adds a modifier to the child while it is focused.
+    pub fn focused_modifier(mut self, modifier: Modifier) -> Self {
+        self.focused_modifier = Some(modifier);
+        self
+    }
+}
+
+impl<V: View> View for Focusable<V> {
+    fn size(&self, proposed: Size) -> Size {
+        self.child.size(proposed)
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        state.register_focusable(id);
+        let context = if state.is_focused(id) {
+            context
+                .with_fg(self.focused_fg)
+                .with_modifier(self.focused_modifier)
+        } else {
+            context
+        };
+        self.child.render(id, context, state, buffer);
+    }
+}
+
+/// A view that turns key events into app messages while it, or a view inside it, is focused.
+pub struct OnKey<V, M> {
+    pub(crate) child: V,
+    pub(crate) handler: MessageHandler<M>,
+}
+
+impl<V: Clone, M> Clone for OnKey<V, M> {
+    fn clone(&self) -> Self {
+        Self {
+            child: self.child.clone(),
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+impl<V, M> private::Sealed for OnKey<V, M> {}
+
+impl<V, M: Send + 'static> OnKey<V, M> {
+    pub(crate) fn new<F>(child: V, handler: F) -> Self
+    where
+        F: Fn(KeyEvent) -> Option<M> + Send + Sync + 'static,
+    {
+        Self {
+            child,
+            handler: Arc::new(move |key, _state| match handler(*key) {
+                Some(message) => KeyResponse::Message(message),
+                None => KeyResponse::Ignored,
+            }),
+        }
+    }
+}
+
+impl<V: View, M: Send + 'static> View for OnKey<V, M> {
+    fn size(&self, proposed: Size) -> Size {
+        self.child.size(proposed)
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        let handler: Arc<dyn Any + Send + Sync> = Arc::new(self.handler.clone());
+        state.register_key_handler(id, KeyHandler::Message(handler));
+        self.child.render(id, context, state, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::render_with_state;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn focused_index(state: &AppState) -> Option<u64> {
+        state.focused().and_then(|id| id.path.last().copied())
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Message {
+        Pressed(u64, char),
+    }
+
+    fn form() -> impl View {
+        vstack((
+            text("A").focusable().on_key(|key| match key.code {
+                KeyCode::Char(c) => Some(Message::Pressed(1, c)),
+                _ => None,
+            }),
+            text("B"),
+            text("C").focusable().on_key(|key| match key.code {
+                KeyCode::Char(c) => Some(Message::Pressed(3, c)),
+                _ => None,
+            }),
+        ))
+    }
+
+    #[test]
+    fn test_first_focusable_is_focused() {
+        let mut state = AppState::new();
+        render_with_state(&form(), &mut state, 10, 3);
+        assert_eq!(focused_index(&state), Some(1));
+    }
+
+    #[test]
+    fn test_tab_traversal_wraps_around() {
+        let mut state = AppState::new();
+        let view = form();
+        render_with_state(&view, &mut state, 10, 3);
+
+        let response = state.dispatch_key::<Message>(&key(KeyCode::Tab));
+        assert_eq!(response, KeyResponse::Consumed);
+        assert_eq!(focused_index(&state), Some(3));
+
+        state.dispatch_key::<Message>(&key(KeyCode::Tab));
+        assert_eq!(focused_index(&state), Some(1));
+
+        state.dispatch_key::<Message>(&key(KeyCode::BackTab));
+        assert_eq!(focused_index(&state), Some(3));
+    }
+
+    #[test]
+    fn test_keys_are_routed_to_focused_view() {
+        let mut state = AppState::new();
+        let view = form();
+        render_with_state(&view, &mut state, 10, 3);
+
+        match state.dispatch_key::<Message>(&key(KeyCode::Char('x'))) {
+            KeyResponse::Message(message) => assert_eq!(message, Message::Pressed(1, 'x')),
+            _ => panic!("expected a message"),
+        }
+
+        state.focus_next();
+        match state.dispatch_key::<Message>(&key(KeyCode::Char('y'))) {
+            KeyResponse::Message(message) => assert_eq!(message, Message::Pressed(3, 'y')),
+            _ => panic!("expected a message"),
+        }
+
+        let response = state.dispatch_key::<Message>(&key(KeyCode::Enter));
+        assert_eq!(response, KeyResponse::Ignored);
+    }
+
+    #[test]
+    fn test_focus_moves_when_focused_view_disappears() {
+        let mut state = AppState::new();
+        render_with_state(&form(), &mut state, 10, 3);
+        state.focus_next();
+        assert_eq!(focused_index(&state), Some(3));
+
+        let view = vstack((text("A").focusable(), text("B")));
+        render_with_state(&view, &mut state, 10, 3);
+        assert_eq!(focused_index(&state), Some(1));
+    }
+
+    #[test]
+    fn test_focused_modifier() {
+        // The first frame already shows the focus, as it is drawn again once focus is given out
+        let view = text("A").focusable().focused_modifier(Modifier::BOLD);
+        let mut buffer = render_with_state(&view, &mut AppState::new(), 1, 1);
+        assert_eq!(buffer.get_mut(0, 0).modifier, Modifier::BOLD);
+    }
+}
@@ -82,12 +82,13 @@ impl<V: View> View for Frame<V> {
             0
         };
 
-        self.child.render(
-            id,
-            context.with_size(size).offset(offset_x, offset_y),
-            state,
-            buffer,
-        );
+        // The child may be larger than the frame, so it is clipped to the frame's rect
+        let context = context.with_size(size);
+        let child_context = context
+            .clone()
+            .clip(context.rect)
+            .offset(offset_x, offset_y);
+        self.child.render(id, child_context, state, buffer);
     }
 }
 
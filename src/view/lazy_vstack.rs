@@ -0,0 +1,197 @@
+use super::*;
+
+/// A vertical list that only builds and renders the rows intersecting its rect.
+///
+/// Every row is assumed to be `row_height` tall, so sizing the list never has to
+/// construct a row. The scroll offset is stored in the [`AppState`] as a
+/// [`ScrollState`], and it responds to the same keys as a [`ScrollView`].
+#[derive(Clone)]
+pub struct LazyVStack<F> {
+    pub(crate) count: usize,
+    pub(crate) row: F,
+    pub(crate) row_height: u16,
+    pub(crate) scroll_to: Option<usize>,
+    pub(crate) scrollbar: bool,
+}
+
+impl<F> private::Sealed for LazyVStack<F> {}
+
+impl<F, V> LazyVStack<F>
+where
+    F: Fn(usize) -> V,
+    V: View,
+{
+    pub fn new(count: usize, row: F) -> Self {
+        Self {
+            count,
+            row,
+            row_height: 1,
+            scroll_to: None,
+            scrollbar: false,
+        }
+    }
+
+    /// Sets the height of every row. Defaults to 1.
+    pub fn row_height(mut self, row_height: u16) -> Self {
+        self.row_height = row_height.max(1);
+        self
+    }
+
+    /// Scrolls just enough to keep the row at `index` visible.
+    pub fn scroll_to(mut self, index: usize) -> Self {
+        self.scroll_to = Some(index);
+        self
+    }
+
+    /// Draws a scrollbar along the right edge when the rows overflow.
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    fn total_height(&self) -> u16 {
+        let total = self.count.saturating_mul(self.row_height as usize);
+        total.min(u16::MAX as usize) as u16
+    }
+}
+
+impl<F, V> View for LazyVStack<F>
+where
+    F: Fn(usize) -> V + 'static,
+    V: View,
+{
+    /// Takes all of the proposed width, as measuring the rows would mean building them.
+    fn size(&self, proposed: Size) -> Size {
+        Size::new(proposed.width, self.total_height().min(proposed.height))
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        let rect = context.rect;
+        let width = rect.size.width.saturating_sub(u16::from(self.scrollbar));
+        let visible = ((rect.size.height / self.row_height) as usize).min(self.count);
+
+        let scroll_state = state.get_mut(id, ScrollState::default);
+        let max_offset = self.count - visible;
+        let mut offset = scroll_state.offset().min(max_offset);
+        if let Some(index) = self
+            .scroll_to
+            .map(|index| index.min(self.count.saturating_sub(1)))
+        {
+            if index < offset {
+                offset = index;
+            } else if index >= offset + visible {
+                offset = (index + 1).saturating_sub(visible).min(max_offset);
+            }
+        }
+        scroll_state.update(offset, visible, self.count);
+        register_scroll_keys(id, state);
+
+        for (row, index) in (offset..offset + visible).enumerate() {
+            let row_context = context
+                .clone()
+                .offset(0, row as u16 * self.row_height)
+                .with_size(Size::new(width, self.row_height));
+            let row_context = row_context.clone().clip(row_context.rect);
+            id.push(index as u64);
+            (self.row)(index).render(id, row_context, state, buffer);
+            id.pop();
+        }
+
+        if self.scrollbar && visible < self.count {
+            draw_scrollbar(
+                Axis::Vertical,
+                &context.with_size(Size::new(rect.size.width, visible as u16 * self.row_height)),
+                offset,
+                visible,
+                self.count,
+                buffer,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::render_with_state;
+    use pretty_assertions::assert_eq;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_lazy_vstack_size() {
+        let view = lazy_vstack(50_000, |i| text(i.to_string()));
+        assert_eq!(view.size(Size::new(10, 20)), Size::new(10, 20));
+
+        let view = lazy_vstack(3, |i| text(i.to_string())).row_height(2);
+        assert_eq!(view.size(Size::new(10, 20)), Size::new(10, 6));
+    }
+
+    #[test]
+    fn test_lazy_vstack_only_builds_visible_rows() {
+        let built = Arc::new(AtomicUsize::new(0));
+        let counter = built.clone();
+        let view = lazy_vstack(50_000, move |i| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            text(format!("Row {}", i))
+        })
+        .scroll_to(25_000);
+
+        let mut state = AppState::new();
+        let expected = ["Row 24998", "Row 24999", "Row 25000"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 9, 3).as_plain_str(),
+            expected
+        );
+        assert_eq!(built.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_lazy_vstack_keeps_offset_between_renders() {
+        let mut state = AppState::new();
+        let view = lazy_vstack(10, |i| text(format!("Row {}", i))).scroll_to(5);
+        assert_eq!(
+            render_with_state(&view, &mut state, 5, 2).as_plain_str(),
+            ["Row 4", "Row 5"].join("\n")
+        );
+
+        let view = lazy_vstack(10, |i| text(format!("Row {}", i))).scroll_to(4);
+        assert_eq!(
+            render_with_state(&view, &mut state, 5, 2).as_plain_str(),
+            ["Row 4", "Row 5"].join("\n")
+        );
+
+        state
+            .get_mut(&ViewId::empty(), ScrollState::default)
+            .page_down();
+        let view = lazy_vstack(10, |i| text(format!("Row {}", i)));
+        assert_eq!(
+            render_with_state(&view, &mut state, 5, 2).as_plain_str(),
+            ["Row 6", "Row 7"].join("\n")
+        );
+    }
+
+    #[test]
+    fn test_lazy_vstack_with_scrollbar() {
+        let view = lazy_vstack(4, |i| text(format!("Row {}", i))).scrollbar(true);
+        let mut state = AppState::new();
+        let expected = ["Row 0┃", "Row 1│"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 2).as_plain_str(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_empty_lazy_vstack() {
+        let view = lazy_vstack(0, |i| text(i.to_string())).scroll_to(0);
+        let mut state = AppState::new();
+        assert_eq!(
+            render_with_state(&view, &mut state, 3, 1).as_plain_str(),
+            "   "
+        );
+
+        let scroll_state = state.get_mut(&ViewId::empty(), ScrollState::default);
+        assert_eq!(scroll_state.offset(), 0);
+    }
+}
@@ -1,14 +1,22 @@
 pub mod alignment;
+pub mod ansi;
 pub mod background;
 pub mod border;
 pub mod context;
 pub mod context_modifier;
+pub mod focus;
 pub mod frame;
 pub mod geometry_reader;
 pub mod identified_view;
+pub mod lazy_vstack;
+pub mod mouse;
 pub mod padding;
+pub mod scroll_view;
 pub mod stack;
+pub mod styled_text;
 pub mod text;
+pub mod text_area;
+pub mod text_field;
 pub mod view_tuple;
 
 use std::fmt::Debug;
@@ -22,12 +30,19 @@ pub use border::{Border, BorderStyle};
 pub use buffer::*;
 pub use context::*;
 pub use context_modifier::*;
+pub use focus::*;
 pub use frame::*;
 pub use geometry_reader::*;
 pub use identified_view::*;
+pub use lazy_vstack::*;
+pub use mouse::*;
 pub use padding::*;
+pub use scroll_view::*;
 pub use stack::*;
+pub use styled_text::*;
 pub use text::*;
+pub use text_area::*;
+pub use text_field::*;
 pub use view_tuple::*;
 
 /// Syntax Examples
@@ -257,6 +272,45 @@ pub trait ViewExtensions: View + Sized {
         ContextModifier::modifier_when(self, condition, Modifier::INVERSE)
     }
 
+    /// Makes the view take part in Tab/Shift-Tab focus traversal.
+    fn focusable(self) -> Focusable<Self> {
+        Focusable {
+            child: self,
+            focused_fg: None,
+            focused_modifier: None,
+        }
+    }
+
+    /// Turns key events into messages while this view, or a view inside it, is focused.
+    /// Returning `None` passes the key on to the app's `update`.
+    fn on_key<M, F>(self, handler: F) -> OnKey<Self, M>
+    where
+        M: Send + 'static,
+        F: Fn(KeyEvent) -> Option<M> + Send + Sync + 'static,
+    {
+        OnKey::new(self, handler)
+    }
+
+    /// Produces a message when the view is clicked with the left mouse button.
+    /// Clicks are only reported when the app runs with [`RunOptions::mouse_capture`].
+    fn on_click<M, F>(self, handler: F) -> OnClick<Self, M>
+    where
+        M: Send + 'static,
+        F: Fn() -> M + Send + Sync + 'static,
+    {
+        OnClick::new(self, handler)
+    }
+
+    /// Produces a message with `true` when the pointer enters the view, and `false` when it leaves.
+    /// The pointer is only tracked when the app runs with [`RunOptions::mouse_capture`].
+    fn on_hover<M, F>(self, handler: F) -> OnHover<Self, M>
+    where
+        M: Send + 'static,
+        F: Fn(bool) -> M + Send + Sync + 'static,
+    {
+        OnHover::new(self, handler)
+    }
+
     fn id<ID: Hash>(self, id: ID) -> IdentifiedView<Self> {
         IdentifiedView::new(id, self)
     }
@@ -277,6 +331,7 @@ pub trait ViewExtensions: View + Sized {
         }
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn as_any(self) -> AnyView
     where
         Self: 'static,
@@ -305,6 +360,7 @@ pub trait ViewExtensions: View + Sized {
         to_string(&buffer)
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn as_str(self) -> String {
         self.render_to_string(Buffer::as_str)
     }
@@ -443,6 +499,11 @@ impl ViewId {
     pub(crate) fn pop(&mut self) {
         self.path.pop().unwrap();
     }
+
+    /// Returns true if this id is `ancestor` or one of its descendants.
+    pub(crate) fn starts_with(&self, ancestor: &ViewId) -> bool {
+        self.path.starts_with(&ancestor.path)
+    }
 }
 
 use std::any::{Any, TypeId};
@@ -451,12 +512,50 @@ use std::collections::HashMap;
 #[derive(Debug)]
 pub struct AppState {
     pub view_map: HashMap<ViewId, Box<dyn Any + Send>>,
+    pub(crate) focus: FocusState,
+    pub(crate) mouse: MouseState,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl AppState {
     pub fn new() -> Self {
         Self {
             view_map: HashMap::new(),
+            focus: FocusState::default(),
+            mouse: MouseState::default(),
+        }
+    }
+
+    /// Clears everything views register while rendering, before a new frame is rendered.
+    pub(crate) fn start_frame(&mut self) {
+        self.focus.start_frame();
+        self.mouse.start_frame();
+    }
+
+    /// Finishes a frame once it is rendered, returning true if the focused view changed.
+    pub(crate) fn finish_frame(&mut self) -> bool {
+        self.mouse.finish_frame();
+        self.focus.finish_frame()
+    }
+
+    /// Renders `view` into `rect` of a blank `buffer`, as one frame.
+    ///
+    /// Focus is only given out once the frame's focusable views are known. When that
+    /// changes the focused view, such as on the first frame, the frame is drawn again so
+    /// that the focused view shows it straight away.
+    pub(crate) fn render_frame(&mut self, view: &impl View, rect: Rect, buffer: &mut Buffer) {
+        self.start_frame();
+        view.render(&mut ViewId::empty(), Context::new(rect), self, buffer);
+        if self.finish_frame() {
+            buffer.clear();
+            self.start_frame();
+            view.render(&mut ViewId::empty(), Context::new(rect), self, buffer);
+            self.finish_frame();
         }
     }
 
@@ -486,7 +585,8 @@ impl View for RenderCounter {
         *count += 1;
 
         let rect = context.rect;
-        buffer.set_string_at(
+        buffer.set_clipped_string_at(
+            context.clip,
             rect.point.x,
             rect.point.y,
             rect.size.width,
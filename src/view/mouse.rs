@@ -0,0 +1,365 @@
+use std::sync::Arc;
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+use super::*;
+
+type ClickHandler<M> = Arc<dyn Fn() -> M + Send + Sync>;
+type HoverHandler<M> = Arc<dyn Fn(bool) -> M + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) enum MouseHandler {
+    /// A type-erased `ClickHandler<M>` for the app's message type.
+    Click(Arc<dyn Any + Send + Sync>),
+    /// A type-erased `HoverHandler<M>` for the app's message type.
+    Hover(Arc<dyn Any + Send + Sync>),
+}
+
+/// The hit-test map, rebuilt from the rects of the views with mouse handlers on every render.
+#[derive(Default)]
+pub struct MouseState {
+    regions: Vec<(Rect, ViewId, MouseHandler)>,
+    /// The views with hover handlers that the pointer was last seen over.
+    hovered: Vec<ViewId>,
+    /// The hover handlers of the hovered views, kept from the last frame.
+    hovered_handlers: Vec<(ViewId, Arc<dyn Any + Send + Sync>)>,
+    /// The hover handlers of hovered views that stopped rendering, owed a leave message.
+    left: Vec<Arc<dyn Any + Send + Sync>>,
+}
+
+impl Debug for MouseState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MouseState")
+            .field("hovered", &self.hovered)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MouseState {
+    pub(crate) fn start_frame(&mut self) {
+        let regions = std::mem::take(&mut self.regions);
+        self.hovered_handlers = regions
+            .into_iter()
+            .filter_map(|(_, id, handler)| match handler {
+                MouseHandler::Hover(handler) if self.hovered.contains(&id) => Some((id, handler)),
+                _ => None,
+            })
+            .collect();
+    }
+
+    /// Stops hovering the views that were not rendered this frame.
+    pub(crate) fn finish_frame(&mut self) {
+        for (id, handler) in std::mem::take(&mut self.hovered_handlers) {
+            let is_rendered = self.regions.iter().any(|(_, region_id, region_handler)| {
+                *region_id == id && matches!(region_handler, MouseHandler::Hover(_))
+            });
+            if !is_rendered && self.hovered.contains(&id) {
+                self.hovered.retain(|hovered| *hovered != id);
+                self.left.push(handler);
+            }
+        }
+    }
+}
+
+impl AppState {
+    /// Registers `handler` for the part of the view that is visible, as views clipped by a
+    /// frame or scroll view may extend past the area they are drawn in.
+    pub(crate) fn register_mouse_handler(
+        &mut self,
+        id: &ViewId,
+        context: &Context,
+        handler: MouseHandler,
+    ) {
+        let rect = context
+            .clip
+            .map_or(context.rect, |clip| clip.intersection(context.rect));
+        if rect.size.width == 0 || rect.size.height == 0 {
+            return;
+        }
+        self.mouse.regions.push((rect, id.clone(), handler));
+    }
+
+    /// Routes a mouse event to the views under the pointer, returning the messages they produce.
+    ///
+    /// A left click goes to the innermost view with a click handler. Any event with a
+    /// position also updates which views are hovered, producing a message for each view
+    /// the pointer has entered or left.
+    pub fn dispatch_mouse<M: 'static>(&mut self, event: &MouseEvent) -> Vec<M> {
+        let (x, y) = (event.column, event.row);
+        let mut messages = Vec::new();
+
+        let under_pointer = self
+            .mouse
+            .regions
+            .iter()
+            .filter(|(rect, _, _)| rect.contains(x, y))
+            .collect::<Vec<_>>();
+
+        let hovered = under_pointer
+            .iter()
+            .filter(|(_, _, handler)| matches!(handler, MouseHandler::Hover(_)))
+            .map(|(_, id, _)| id.clone())
+            .collect::<Vec<_>>();
+        for (_, id, handler) in &self.mouse.regions {
+            let MouseHandler::Hover(handler) = handler else {
+                continue;
+            };
+            let Some(handler) = handler.downcast_ref::<HoverHandler<M>>() else {
+                continue;
+            };
+            match (self.mouse.hovered.contains(id), hovered.contains(id)) {
+                (false, true) => messages.push(handler(true)),
+                (true, false) => messages.push(handler(false)),
+                _ => {}
+            }
+        }
+
+        if let MouseEventKind::Down(MouseButton::Left) = event.kind {
+            let click = under_pointer
+                .iter()
+                .rev()
+                .find_map(|(_, _, handler)| match handler {
+                    MouseHandler::Click(handler) => handler.downcast_ref::<ClickHandler<M>>(),
+                    MouseHandler::Hover(_) => None,
+                });
+            if let Some(handler) = click {
+                messages.push(handler());
+            }
+        }
+
+        self.mouse.hovered = hovered;
+        messages
+    }
+
+    /// Returns a leave message for each hovered view that stopped rendering, such as a
+    /// row filtered out of a list, so the app does not keep it marked as hovered.
+    pub fn take_hover_leaves<M: 'static>(&mut self) -> Vec<M> {
+        std::mem::take(&mut self.mouse.left)
+            .iter()
+            .filter_map(|handler| handler.downcast_ref::<HoverHandler<M>>())
+            .map(|handler| handler(false))
+            .collect()
+    }
+}
+
+/// A view that produces a message when it is clicked.
+pub struct OnClick<V, M> {
+    pub(crate) child: V,
+    pub(crate) handler: ClickHandler<M>,
+}
+
+impl<V: Clone, M> Clone for OnClick<V, M> {
+    fn clone(&self) -> Self {
+        Self {
+            child: self.child.clone(),
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+impl<V, M> private::Sealed for OnClick<V, M> {}
+
+impl<V, M: Send + 'static> OnClick<V, M> {
+    pub(crate) fn new<F>(child: V, handler: F) -> Self
+    where
+        F: Fn() -> M + Send + Sync + 'static,
+    {
+        Self {
+            child,
+            handler: Arc::new(handler),
+        }
+    }
+}
+
+impl<V: View, M: Send + 'static> View for OnClick<V, M> {
+    fn size(&self, proposed: Size) -> Size {
+        self.child.size(proposed)
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        let handler = MouseHandler::Click(Arc::new(self.handler.clone()));
+        state.register_mouse_handler(id, &context, handler);
+        self.child.render(id, context, state, buffer);
+    }
+}
+
+/// A view that produces a message when the pointer enters or leaves it.
+pub struct OnHover<V, M> {
+    pub(crate) child: V,
+    pub(crate) handler: HoverHandler<M>,
+}
+
+impl<V: Clone, M> Clone for OnHover<V, M> {
+    fn clone(&self) -> Self {
+        Self {
+            child: self.child.clone(),
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+impl<V, M> private::Sealed for OnHover<V, M> {}
+
+impl<V, M: Send + 'static> OnHover<V, M> {
+    pub(crate) fn new<F>(child: V, handler: F) -> Self
+    where
+        F: Fn(bool) -> M + Send + Sync + 'static,
+    {
+        Self {
+            child,
+            handler: Arc::new(handler),
+        }
+    }
+}
+
+impl<V: View, M: Send + 'static> View for OnHover<V, M> {
+    fn size(&self, proposed: Size) -> Size {
+        self.child.size(proposed)
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        let handler = MouseHandler::Hover(Arc::new(self.handler.clone()));
+        state.register_mouse_handler(id, &context, handler);
+        self.child.render(id, context, state, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::render_with_state;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Message {
+        Clicked(&'static str),
+        Hovered(&'static str, bool),
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn click(column: u16, row: u16) -> MouseEvent {
+        mouse(MouseEventKind::Down(MouseButton::Left), column, row)
+    }
+
+    fn buttons() -> impl View {
+        vstack((
+            text("OK").on_click(|| Message::Clicked("ok")),
+            text("Cancel").on_click(|| Message::Clicked("cancel")),
+        ))
+        .on_click(|| Message::Clicked("stack"))
+    }
+
+    #[test]
+    fn test_click_goes_to_innermost_view() {
+        let mut state = AppState::new();
+        render_with_state(&buttons(), &mut state, 10, 3);
+
+        let messages = state.dispatch_mouse::<Message>(&click(1, 0));
+        assert_eq!(messages, [Message::Clicked("ok")]);
+
+        let messages = state.dispatch_mouse::<Message>(&click(5, 1));
+        assert_eq!(messages, [Message::Clicked("cancel")]);
+
+        let messages = state.dispatch_mouse::<Message>(&click(4, 0));
+        assert_eq!(messages, [Message::Clicked("stack")]);
+    }
+
+    #[test]
+    fn test_click_outside_frame_is_ignored() {
+        let view = vstack((
+            text("OK").on_click(|| Message::Clicked("ok")),
+            text("Cancel").on_click(|| Message::Clicked("cancel")),
+        ))
+        .frame(None, None, None, Some(1), Alignment::TOP_LEFT);
+        let mut state = AppState::new();
+        render_with_state(&view, &mut state, 10, 3);
+
+        assert_eq!(
+            state.dispatch_mouse::<Message>(&click(0, 0)),
+            [Message::Clicked("ok")]
+        );
+        assert_eq!(state.dispatch_mouse::<Message>(&click(0, 1)), []);
+    }
+
+    #[test]
+    fn test_only_left_button_down_clicks() {
+        let mut state = AppState::new();
+        render_with_state(&buttons(), &mut state, 10, 3);
+
+        let up = mouse(MouseEventKind::Up(MouseButton::Left), 0, 0);
+        assert_eq!(state.dispatch_mouse::<Message>(&up), []);
+
+        let right = mouse(MouseEventKind::Down(MouseButton::Right), 0, 0);
+        assert_eq!(state.dispatch_mouse::<Message>(&right), []);
+    }
+
+    #[test]
+    fn test_hover_enter_and_leave() {
+        let view = hstack((
+            text("A").on_hover(|hovered| Message::Hovered("a", hovered)),
+            text("B").on_hover(|hovered| Message::Hovered("b", hovered)),
+        ));
+        let mut state = AppState::new();
+        render_with_state(&view, &mut state, 10, 3);
+
+        let moved = |column| mouse(MouseEventKind::Moved, column, 0);
+        assert_eq!(
+            state.dispatch_mouse::<Message>(&moved(0)),
+            [Message::Hovered("a", true)]
+        );
+        assert_eq!(state.dispatch_mouse::<Message>(&moved(0)), []);
+        assert_eq!(
+            state.dispatch_mouse::<Message>(&moved(2)),
+            [Message::Hovered("a", false), Message::Hovered("b", true)]
+        );
+        assert_eq!(
+            state.dispatch_mouse::<Message>(&moved(5)),
+            [Message::Hovered("b", false)]
+        );
+    }
+
+    #[test]
+    fn test_hovered_view_that_stops_rendering_is_left() {
+        let view = |show_b: bool| {
+            hstack((
+                text("A").on_hover(|hovered| Message::Hovered("a", hovered)),
+                text("B")
+                    .on_hover(|hovered| Message::Hovered("b", hovered))
+                    .visible(show_b),
+            ))
+        };
+        let mut state = AppState::new();
+        render_with_state(&view(true), &mut state, 10, 3);
+        let moved = mouse(MouseEventKind::Moved, 2, 0);
+        assert_eq!(
+            state.dispatch_mouse::<Message>(&moved),
+            [Message::Hovered("b", true)]
+        );
+
+        render_with_state(&view(true), &mut state, 10, 3);
+        assert_eq!(state.take_hover_leaves::<Message>(), []);
+
+        render_with_state(&view(false), &mut state, 10, 3);
+        assert_eq!(
+            state.take_hover_leaves::<Message>(),
+            [Message::Hovered("b", false)]
+        );
+        assert_eq!(state.take_hover_leaves::<Message>(), []);
+
+        // Once it renders again, the pointer enters it again
+        render_with_state(&view(true), &mut state, 10, 3);
+        assert_eq!(
+            state.dispatch_mouse::<Message>(&moved),
+            [Message::Hovered("b", true)]
+        );
+    }
+}
@@ -42,7 +42,6 @@ impl<V: View> View for Padding<V> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::tests::assert_rendered_view;
     use crate::*;
 
@@ -0,0 +1,473 @@
+use std::sync::Arc;
+
+use super::*;
+
+/// The axis along which a [`ScrollView`] stacks and scrolls its children.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+/// The scroll position of a [`ScrollView`].
+///
+/// This is stored in the [`AppState`] keyed by the view's [`ViewId`], so the
+/// offset survives between renders instead of being recomputed every frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScrollState {
+    /// The index of the first visible child.
+    offset: usize,
+    /// The number of children that fit in the viewport during the last render.
+    visible: usize,
+    /// The number of children during the last render.
+    len: usize,
+}
+
+impl ScrollState {
+    /// Returns the index of the first visible child.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of children that were visible during the last render.
+    pub fn visible(&self) -> usize {
+        self.visible
+    }
+
+    /// Scrolls by the given number of children. Negative values scroll towards the start.
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.offset = self
+            .offset
+            .saturating_add_signed(delta)
+            .min(self.max_offset());
+    }
+
+    /// Scrolls back by one page.
+    pub fn page_up(&mut self) {
+        self.scroll_by(-(self.visible.max(1) as isize));
+    }
+
+    /// Scrolls forward by one page.
+    pub fn page_down(&mut self) {
+        self.scroll_by(self.visible.max(1) as isize);
+    }
+
+    pub fn scroll_to_start(&mut self) {
+        self.offset = 0;
+    }
+
+    pub fn scroll_to_end(&mut self) {
+        self.offset = self.max_offset();
+    }
+
+    /// The offset that shows the last child at the end of the viewport, as of the last render.
+    fn max_offset(&self) -> usize {
+        self.len.saturating_sub(self.visible)
+    }
+
+    pub(crate) fn update(&mut self, offset: usize, visible: usize, len: usize) {
+        *self = ScrollState {
+            offset,
+            visible,
+            len,
+        };
+    }
+}
+
+/// A view that stacks its children along an [`Axis`] and only renders the ones
+/// that fit, starting from the persisted [`ScrollState`] offset.
+///
+/// While the scroll view is inside a focused view, Page Up/Page Down and Home/End scroll it.
+#[derive(Clone, Debug)]
+pub struct ScrollView<VT> {
+    pub(crate) children: VT,
+    pub(crate) axis: Axis,
+    pub(crate) scroll_to: Option<usize>,
+    pub(crate) scrollbar: bool,
+}
+
+impl<VT> private::Sealed for ScrollView<VT> {}
+
+impl<VT: ViewTuple> ScrollView<VT> {
+    pub fn new(children: VT, axis: Axis) -> Self {
+        Self {
+            children,
+            axis,
+            scroll_to: None,
+            scrollbar: false,
+        }
+    }
+
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Scrolls just enough to keep the child at `index` visible.
+    pub fn scroll_to(mut self, index: usize) -> Self {
+        self.scroll_to = Some(index);
+        self
+    }
+
+    /// Draws a scrollbar along the trailing edge when the content overflows.
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// The size of the area available to the children, excluding the scrollbar.
+    fn viewport(&self, proposed: Size) -> Size {
+        match (self.scrollbar, self.axis) {
+            (false, _) => proposed,
+            (true, Axis::Vertical) => proposed.inset_by(0, 1, 0, 0),
+            (true, Axis::Horizontal) => proposed.inset_by(0, 0, 0, 1),
+        }
+    }
+
+    fn viewport_length(&self, viewport: Size) -> u16 {
+        match self.axis {
+            Axis::Vertical => viewport.height,
+            Axis::Horizontal => viewport.width,
+        }
+    }
+
+    /// Returns the size of each child along the scrolling axis and across it.
+    fn child_extents(&self, viewport: Size) -> Vec<(u16, u16)> {
+        self.children
+            .make_iterator()
+            .map(|child| {
+                let size = child.value.size(viewport);
+                match self.axis {
+                    Axis::Vertical => (size.height, size.width),
+                    Axis::Horizontal => (size.width, size.height),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the smallest offset from which every child up to and including `last` fits.
+fn offset_ending_at(extents: &[(u16, u16)], last: usize, length: u16) -> usize {
+    let mut total: u16 = 0;
+    let mut start = last + 1;
+    while start > 0 {
+        let extent = extents[start - 1].0;
+        if total.saturating_add(extent) > length && start <= last {
+            break;
+        }
+        total = total.saturating_add(extent);
+        start -= 1;
+    }
+    start
+}
+
+/// Returns the number of children that fit in `length`, starting at `offset`.
+/// A child that is larger than the viewport is still counted, and clipped when rendered.
+fn visible_count(extents: &[(u16, u16)], offset: usize, length: u16) -> usize {
+    let mut total: u16 = 0;
+    let count = extents[offset..]
+        .iter()
+        .take_while(|(extent, _)| {
+            total = total.saturating_add(*extent);
+            total <= length
+        })
+        .count();
+    count.max(usize::from(offset < extents.len()))
+}
+
+impl<VT: ViewTuple + 'static> View for ScrollView<VT> {
+    fn size(&self, proposed: Size) -> Size {
+        let viewport = self.viewport(proposed);
+        let extents = self.child_extents(viewport);
+        let main = extents
+            .iter()
+            .fold(0u16, |total, (extent, _)| total.saturating_add(*extent));
+        let cross = extents.iter().map(|(_, cross)| *cross).max().unwrap_or(0);
+        let scrollbar = u16::from(self.scrollbar);
+        let size = match self.axis {
+            Axis::Vertical => Size::new(cross.saturating_add(scrollbar), main),
+            Axis::Horizontal => Size::new(main, cross.saturating_add(scrollbar)),
+        };
+        size.min(proposed)
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        let viewport = self.viewport(context.rect.size);
+        let length = self.viewport_length(viewport);
+        let extents = self.child_extents(viewport);
+
+        let scroll_state = state.get_mut(id, ScrollState::default);
+        let last = extents.len().saturating_sub(1);
+        let max_offset = if extents.is_empty() {
+            0
+        } else {
+            offset_ending_at(&extents, last, length)
+        };
+        let mut offset = scroll_state.offset.min(max_offset);
+        let scroll_to = self.scroll_to.filter(|_| !extents.is_empty());
+        if let Some(index) = scroll_to.map(|index| index.min(last)) {
+            if index < offset {
+                offset = index;
+            } else if index >= offset + visible_count(&extents, offset, length) {
+                offset = offset_ending_at(&extents, index, length);
+            }
+        }
+        let visible = visible_count(&extents, offset, length);
+        scroll_state.update(offset, visible, extents.len());
+        register_scroll_keys(id, state);
+
+        let mut position: u16 = 0;
+        self.children
+            .make_iterator()
+            .zip(extents.iter())
+            .skip(offset)
+            .take(visible)
+            .for_each(|(child, (extent, _))| {
+                let extent = (*extent).min(length.saturating_sub(position));
+                let child_context = match self.axis {
+                    Axis::Vertical => context
+                        .clone()
+                        .offset(0, position)
+                        .with_size(Size::new(viewport.width, extent)),
+                    Axis::Horizontal => context
+                        .clone()
+                        .offset(position, 0)
+                        .with_size(Size::new(extent, viewport.height)),
+                };
+                let child_context = child_context.clone().clip(child_context.rect);
+                id.push(child.id);
+                child.value.render(id, child_context, state, buffer);
+                id.pop();
+                position += extent;
+            });
+
+        if self.scrollbar && visible < extents.len() {
+            draw_scrollbar(self.axis, &context, offset, visible, extents.len(), buffer);
+        }
+    }
+}
+
+/// Lets the scroll view at `id` be paged with Page Up/Page Down and Home/End while focused.
+pub(crate) fn register_scroll_keys(id: &ViewId, state: &mut AppState) {
+    let scroll_id = id.clone();
+    let handler = move |key: &KeyEvent, state: &mut AppState| {
+        let scroll_state = state.get_mut(&scroll_id, ScrollState::default);
+        match key.code {
+            KeyCode::PageUp => scroll_state.page_up(),
+            KeyCode::PageDown => scroll_state.page_down(),
+            KeyCode::Home => scroll_state.scroll_to_start(),
+            KeyCode::End => scroll_state.scroll_to_end(),
+            _ => return false,
+        }
+        true
+    };
+    state.register_key_handler(id, KeyHandler::State(Arc::new(handler)));
+}
+
+/// Draws a scrollbar along the trailing edge of the context's rect.
+pub(crate) fn draw_scrollbar(
+    axis: Axis,
+    context: &Context,
+    offset: usize,
+    visible: usize,
+    len: usize,
+    buffer: &mut Buffer,
+) {
+    let rect = context.rect;
+    let track = match axis {
+        Axis::Vertical => rect.size.height,
+        Axis::Horizontal => rect.size.width,
+    };
+    let thumb_length = ((track as usize * visible) / len).max(1) as u16;
+    let max_thumb_start = track.saturating_sub(thumb_length);
+    let max_offset = len.saturating_sub(visible).max(1);
+    let thumb_start = ((max_thumb_start as usize * offset) / max_offset) as u16;
+    let thumb = thumb_start..thumb_start + thumb_length;
+
+    for position in 0..track {
+        let (x, y, symbol) = match axis {
+            Axis::Vertical => (
+                rect.right().saturating_sub(1),
+                rect.top() + position,
+                if thumb.contains(&position) {
+                    '┃'
+                } else {
+                    '│'
+                },
+            ),
+            Axis::Horizontal => (
+                rect.left() + position,
+                rect.bottom().saturating_sub(1),
+                if thumb.contains(&position) {
+                    '━'
+                } else {
+                    '─'
+                },
+            ),
+        };
+        let modifier = if thumb.contains(&position) {
+            context.modifier
+        } else {
+            context.modifier | Modifier::DIM
+        };
+        buffer.set_clipped_char_at(context.clip, x, y, symbol, context.fg, None, modifier);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::render_with_state;
+    use pretty_assertions::assert_eq;
+
+    fn items() -> Vec<IdentifiedView<Text>> {
+        (0..6)
+            .map(|i| text(format!("Item {}", i)).id(i))
+            .collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn test_scroll_view_size() {
+        let view = scroll_view(items());
+        assert_eq!(view.size(Size::max()), Size::new(6, 6));
+        assert_eq!(view.size(Size::new(10, 3)), Size::new(6, 3));
+        assert_eq!(view.scrollbar(true).size(Size::new(10, 3)), Size::new(7, 3));
+    }
+
+    #[test]
+    fn test_scroll_view_renders_from_start() {
+        let view = scroll_view(items());
+        let mut state = AppState::new();
+        let expected = ["Item 0", "Item 1", "Item 2"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 3).as_plain_str(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_scroll_view_keeps_child_visible() {
+        let mut state = AppState::new();
+
+        let view = scroll_view(items()).scroll_to(4);
+        let expected = ["Item 2", "Item 3", "Item 4"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 3).as_plain_str(),
+            expected
+        );
+
+        // Moving the selection within the viewport does not scroll.
+        let view = scroll_view(items()).scroll_to(3);
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 3).as_plain_str(),
+            expected
+        );
+
+        let view = scroll_view(items()).scroll_to(1);
+        let expected = ["Item 1", "Item 2", "Item 3"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 3).as_plain_str(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_scroll_view_page_down_and_up() {
+        let mut state = AppState::new();
+        let view = scroll_view(items());
+        render_with_state(&view, &mut state, 6, 2);
+
+        state
+            .get_mut(&ViewId::empty(), ScrollState::default)
+            .page_down();
+        let expected = ["Item 2", "Item 3"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 2).as_plain_str(),
+            expected
+        );
+
+        state
+            .get_mut(&ViewId::empty(), ScrollState::default)
+            .scroll_to_end();
+        let expected = ["Item 4", "Item 5"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 2).as_plain_str(),
+            expected
+        );
+
+        state
+            .get_mut(&ViewId::empty(), ScrollState::default)
+            .page_up();
+        let expected = ["Item 2", "Item 3"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 2).as_plain_str(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_scrolling_stops_at_the_last_page() {
+        let mut state = AppState::new();
+        let view = scroll_view(items());
+        render_with_state(&view, &mut state, 6, 2);
+
+        let scroll_state = state.get_mut(&ViewId::empty(), ScrollState::default);
+        scroll_state.scroll_by(10);
+        assert_eq!(scroll_state.offset(), 4);
+        scroll_state.page_down();
+        assert_eq!(scroll_state.offset(), 4);
+    }
+
+    #[test]
+    fn test_empty_scroll_view() {
+        let view = scroll_view(Vec::<IdentifiedView<Text>>::new()).scroll_to(0);
+        let mut state = AppState::new();
+        assert_eq!(
+            render_with_state(&view, &mut state, 3, 1).as_plain_str(),
+            "   "
+        );
+    }
+
+    #[test]
+    fn test_scroll_view_pages_with_keys_when_focused() {
+        let mut state = AppState::new();
+        let view = scroll_view(items()).focusable();
+        render_with_state(&view, &mut state, 6, 2);
+
+        let page_down = KeyEvent::new(KeyCode::PageDown, crossterm::event::KeyModifiers::NONE);
+        assert_eq!(state.dispatch_key::<()>(&page_down), KeyResponse::Consumed);
+        let expected = ["Item 2", "Item 3"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 6, 2).as_plain_str(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_scroll_view_with_scrollbar() {
+        let view = scroll_view(items()).scrollbar(true).scroll_to(5);
+        let mut state = AppState::new();
+        let expected = [
+            "Item 3│", //
+            "Item 4│", //
+            "Item 5┃", //
+        ]
+        .join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 7, 3).as_plain_str(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_horizontal_scroll_view() {
+        let view = scroll_view((text("AA"), text("BB"), text("CC")))
+            .axis(Axis::Horizontal)
+            .scroll_to(2);
+        let mut state = AppState::new();
+        assert_eq!(
+            render_with_state(&view, &mut state, 5, 1).as_plain_str(),
+            "BBCC "
+        );
+    }
+}
@@ -40,7 +40,7 @@ impl<VT: ViewTuple> HStack<VT> {
                 (index, child, upper - lower)
             })
             .collect::<Vec<_>>();
-        views_with_flex.sort_by(|a, b| a.2.cmp(&b.2));
+        views_with_flex.sort_by_key(|a| a.2);
 
         let total = self.children.length();
         let mut remaining_width = proposed.width;
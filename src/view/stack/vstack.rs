@@ -41,7 +41,7 @@ impl<VT: ViewTuple> VStack<VT> {
                 (index, child, upper - lower)
             })
             .collect::<Vec<_>>();
-        views_with_flex.sort_by(|a, b| a.2.cmp(&b.2));
+        views_with_flex.sort_by_key(|a| a.2);
 
         let mut remaining_height = proposed.height;
         let mut max_width = 0;
@@ -0,0 +1,263 @@
+use compact_str::CompactString;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::*;
+
+/// A run of text with its own colors and modifiers, for use in a [`StyledText`].
+///
+/// Anything a span leaves unset is inherited from the surrounding context.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub(crate) text: CompactString,
+    pub(crate) fg: Option<Color>,
+    pub(crate) bg: Option<Color>,
+    pub(crate) modifier: Modifier,
+}
+
+impl Span {
+    pub fn new(text: impl AsRef<str>) -> Self {
+        Self {
+            text: text.as_ref().into(),
+            fg: None,
+            bg: None,
+            modifier: Modifier::empty(),
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Adds to the span's modifiers.
+    pub fn modifier(mut self, modifier: Modifier) -> Self {
+        self.modifier |= modifier;
+        self
+    }
+
+    pub fn green(self) -> Self {
+        self.color(Color::DarkGreen)
+    }
+
+    pub fn red(self) -> Self {
+        self.color(Color::DarkRed)
+    }
+
+    pub fn blue(self) -> Self {
+        self.color(Color::DarkBlue)
+    }
+
+    pub fn yellow(self) -> Self {
+        self.color(Color::DarkYellow)
+    }
+
+    pub fn white(self) -> Self {
+        self.color(Color::White)
+    }
+
+    pub fn black(self) -> Self {
+        self.color(Color::Black)
+    }
+
+    pub fn cyan(self) -> Self {
+        self.color(Color::DarkCyan)
+    }
+
+    pub fn magenta(self) -> Self {
+        self.color(Color::DarkMagenta)
+    }
+
+    pub fn bold(self) -> Self {
+        self.modifier(Modifier::BOLD)
+    }
+
+    pub fn italic(self) -> Self {
+        self.modifier(Modifier::ITALIC)
+    }
+
+    pub fn underline(self) -> Self {
+        self.modifier(Modifier::UNDERLINE)
+    }
+
+    pub fn dim(self) -> Self {
+        self.modifier(Modifier::DIM)
+    }
+
+    pub fn inverse(self) -> Self {
+        self.modifier(Modifier::INVERSE)
+    }
+
+    pub fn strikethrough(self) -> Self {
+        self.modifier(Modifier::STRIKETHROUGH)
+    }
+}
+
+impl From<&str> for Span {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for Span {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// Text made of [`Span`]s that each carry their own style.
+///
+/// The spans are laid out as a single piece of text, so they wrap and break at
+/// `\n` exactly like a [`Text`] would, with each grapheme keeping the style of
+/// the span it came from.
+#[derive(Clone, Debug)]
+pub struct StyledText {
+    pub(crate) spans: Vec<Span>,
+    /// The text of every span joined together.
+    pub(crate) text: String,
+    /// The byte offset in `text` where each span starts.
+    pub(crate) starts: Vec<usize>,
+    pub(crate) wrap: Wrap,
+}
+
+impl private::Sealed for StyledText {}
+
+impl StyledText {
+    pub fn new(spans: impl IntoIterator<Item = Span>) -> Self {
+        let spans = spans.into_iter().collect::<Vec<_>>();
+        let mut text = String::new();
+        let mut starts = Vec::with_capacity(spans.len());
+        for span in &spans {
+            starts.push(text.len());
+            text.push_str(&span.text);
+        }
+        Self {
+            spans,
+            text,
+            starts,
+            wrap: Wrap::Word,
+        }
+    }
+
+    /// Sets how lines wider than the frame are broken up. Defaults to [`Wrap::Word`].
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Returns the span that the byte at `offset` belongs to.
+    fn span_at(&self, offset: usize) -> &Span {
+        let index = self.starts.partition_point(|start| *start <= offset);
+        &self.spans[index - 1]
+    }
+}
+
+impl View for StyledText {
+    fn size(&self, proposed: Size) -> Size {
+        let lines = wrap_ranges(&self.text, proposed.width, self.wrap);
+        let width = lines
+            .iter()
+            .map(|range| self.text[range.clone()].width())
+            .max()
+            .unwrap_or(0);
+        Size::new(
+            width.min(proposed.width as usize) as u16,
+            lines.len().min(u16::MAX as usize) as u16,
+        )
+    }
+
+    fn render(
+        &self,
+        _id: &mut ViewId,
+        context: Context,
+        _state: &mut AppState,
+        buffer: &mut Buffer,
+    ) {
+        let rect = context.rect;
+        let lines = wrap_ranges(&self.text, rect.size.width, self.wrap);
+        for (row, range) in lines
+            .into_iter()
+            .take(rect.size.height as usize)
+            .enumerate()
+        {
+            let mut x = rect.left();
+            for (index, grapheme) in self.text[range.clone()].grapheme_indices(true) {
+                let width = grapheme.width() as u16;
+                if x + width > rect.right() {
+                    break;
+                }
+                let span = self.span_at(range.start + index);
+                buffer.set_clipped_string_at(
+                    context.clip,
+                    x,
+                    rect.top() + row as u16,
+                    width,
+                    grapheme,
+                    span.fg.unwrap_or(context.fg),
+                    span.bg,
+                    context.modifier | span.modifier,
+                );
+                x += width;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::render_with_state;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_styled_text_size() {
+        let view = styled_text([span("Hello, "), span("World").bold()]);
+        assert_eq!(view.size(Size::MAX), Size::new(12, 1));
+        assert_eq!(view.size(Size::new(8, 10)), Size::new(6, 2));
+    }
+
+    #[test]
+    fn test_styled_text_keeps_span_styles() {
+        let view = styled_text([
+            span("a "),
+            span("red").red().background(Color::White),
+            span(" b").bold(),
+        ])
+        .color(Color::Blue);
+        let mut buffer = render_with_state(&view, &mut AppState::new(), 7, 1);
+        assert_eq!(buffer.as_plain_str(), "a red b");
+
+        let plain = buffer.get_mut(0, 0).clone();
+        assert_eq!((plain.fg, plain.modifier), (Color::Blue, Modifier::empty()));
+
+        let red = buffer.get_mut(2, 0).clone();
+        assert_eq!((red.fg, red.bg), (Color::DarkRed, Color::White));
+
+        let bold = buffer.get_mut(6, 0).clone();
+        assert_eq!((bold.fg, bold.modifier), (Color::Blue, Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_styled_text_wraps_across_spans() {
+        let view = styled_text([span("one tw"), span("o three").green()]);
+        let mut buffer = render_with_state(&view, &mut AppState::new(), 7, 2);
+        assert_eq!(buffer.as_plain_str(), ["one two", "three  "].join("\n"));
+
+        assert_eq!(buffer.get_mut(5, 0).fg, Color::Reset);
+        assert_eq!(buffer.get_mut(6, 0).fg, Color::DarkGreen);
+        assert_eq!(buffer.get_mut(0, 1).fg, Color::DarkGreen);
+    }
+
+    #[test]
+    fn test_styled_text_newlines() {
+        let view = styled_text([span("a\nb"), span("c\nd").dim()]);
+        assert_eq!(view.size(Size::MAX), Size::new(2, 3));
+        let buffer = render_with_state(&view, &mut AppState::new(), 2, 3);
+        assert_eq!(buffer.as_plain_str(), ["a ", "bc", "d "].join("\n"));
+    }
+}
@@ -1,20 +1,63 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
 use super::*;
 use compact_str::CompactString;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// How a line of text that is wider than its frame is broken up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Wrap {
+    /// Breaks lines between words, splitting any word that is wider than a whole line.
+    #[default]
+    Word,
+    /// Breaks lines between any two graphemes.
+    Character,
+    /// Only breaks lines at `\n`, clipping anything past the edge of the frame.
+    None,
+}
+
+/// Where a line of text that is wider than its frame is cut and replaced with `…`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Truncation {
+    Start,
+    Middle,
+    End,
+}
 
 #[derive(Clone, Debug)]
 pub struct Text {
     pub(crate) text: CompactString,
+    /// The width of the widest line.
     pub(crate) width: u16,
+    pub(crate) wrap: Wrap,
+    pub(crate) truncation: Option<Truncation>,
 }
 
 impl private::Sealed for Text {}
 
+impl Text {
+    /// Sets how lines wider than the frame are broken up. Defaults to [`Wrap::Word`].
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Truncates lines wider than the frame with an ellipsis, instead of wrapping them.
+    pub fn truncate(mut self, truncation: Truncation) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+}
+
 impl View for Text {
     fn size(&self, proposed: Size) -> Size {
-        Size {
-            width: self.width.min(proposed.width),
-            height: 1,
+        if self.width <= proposed.width && !self.text.contains('\n') {
+            return Size::new(self.width, 1);
         }
+        let lines = layout_lines(&self.text, proposed.width, self.wrap, self.truncation);
+        size_for_lines(&lines, proposed.width)
     }
 
     fn render(
@@ -24,16 +67,13 @@ impl View for Text {
         _state: &mut AppState,
         buffer: &mut Buffer,
     ) {
-        let rect = context.rect;
-        buffer.set_string_at(
-            rect.left(),
-            rect.top(),
-            self.width.min(rect.size.width),
+        let lines = layout_lines(
             &self.text,
-            context.fg,
-            None,
-            context.modifier,
+            context.rect.size.width,
+            self.wrap,
+            self.truncation,
         );
+        render_lines(&lines, context, buffer);
     }
 }
 
@@ -75,31 +115,169 @@ impl View for String {
 
 #[inline]
 fn size_for_text(text: &str, proposed: Size) -> Size {
-    Size {
-        width: (text.len() as u16).min(proposed.width),
-        height: 1,
-    }
+    let lines = layout_lines(text, proposed.width, Wrap::Word, None);
+    size_for_lines(&lines, proposed.width)
 }
 
 #[inline]
 fn render_text(text: &str, _id: &mut ViewId, context: Context, buffer: &mut Buffer) {
+    let lines = layout_lines(text, context.rect.size.width, Wrap::Word, None);
+    render_lines(&lines, context, buffer);
+}
+
+/// Measures laid out lines, clipping any that are wider than `max_width`.
+fn size_for_lines(lines: &[Cow<str>], max_width: u16) -> Size {
+    let width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+    Size::new(
+        width.min(max_width as usize) as u16,
+        lines.len().min(u16::MAX as usize) as u16,
+    )
+}
+
+fn render_lines(lines: &[Cow<str>], context: Context, buffer: &mut Buffer) {
     let rect = context.rect;
-    let width = size_for_text(text, rect.size).width;
-    buffer.set_string_at(
-        rect.left(),
-        rect.top(),
-        width,
-        &text,
-        context.fg,
-        None,
-        context.modifier,
-    );
+    for (row, line) in lines.iter().take(rect.size.height as usize).enumerate() {
+        buffer.set_clipped_string_at(
+            context.clip,
+            rect.left(),
+            rect.top() + row as u16,
+            rect.size.width,
+            line,
+            context.fg,
+            None,
+            context.modifier,
+        );
+    }
+}
+
+/// Splits `text` at each `\n`, then wraps or truncates each line to fit in `width` columns.
+pub(crate) fn layout_lines(
+    text: &str,
+    width: u16,
+    wrap: Wrap,
+    truncation: Option<Truncation>,
+) -> Vec<Cow<'_, str>> {
+    let Some(truncation) = truncation else {
+        let ranges = wrap_ranges(text, width, wrap);
+        return ranges
+            .into_iter()
+            .map(|range| Cow::Borrowed(&text[range]))
+            .collect();
+    };
+    let width = width as usize;
+    text.split('\n')
+        .map(|line| {
+            if line.width() <= width || width == 0 {
+                Cow::Borrowed(line)
+            } else {
+                Cow::Owned(truncate(line, width, truncation))
+            }
+        })
+        .collect()
+}
+
+/// Splits `text` at each `\n`, then wraps each line to fit in `width` columns.
+/// Returns the byte range of every resulting line.
+pub(crate) fn wrap_ranges(text: &str, width: u16, wrap: Wrap) -> Vec<Range<usize>> {
+    let width = width as usize;
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+    for line in text.split('\n') {
+        let range = offset..offset + line.len();
+        offset = range.end + 1;
+        if line.width() <= width || width == 0 {
+            ranges.push(range);
+            continue;
+        }
+        let wrapped = match wrap {
+            Wrap::None => {
+                ranges.push(range);
+                continue;
+            }
+            Wrap::Character => wrap_segments(line, line.grapheme_indices(true), width),
+            Wrap::Word => wrap_segments(line, line.split_word_bound_indices(), width),
+        };
+        let shift = |wrapped: Range<usize>| wrapped.start + range.start..wrapped.end + range.start;
+        ranges.extend(wrapped.into_iter().map(shift));
+    }
+    ranges
+}
+
+/// Fills lines with segments, breaking at whitespace and splitting segments that are
+/// wider than a whole line. Whitespace at a break is dropped.
+fn wrap_segments<'a>(
+    line: &'a str,
+    segments: impl Iterator<Item = (usize, &'a str)>,
+    width: usize,
+) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let (mut start, mut end, mut line_width) = (0, 0, 0);
+    for (index, segment) in segments {
+        let segment_width = segment.width();
+        if line_width + segment_width <= width {
+            end = index + segment.len();
+            line_width += segment_width;
+            continue;
+        }
+        if line_width > 0 {
+            ranges.push(start..start + line[start..end].trim_end().len());
+            (start, end, line_width) = (index, index, 0);
+        }
+        if segment.trim().is_empty() {
+            (start, end) = (index + segment.len(), index + segment.len());
+            continue;
+        }
+        for (grapheme_index, grapheme) in segment.grapheme_indices(true) {
+            let grapheme_width = grapheme.width();
+            if line_width > 0 && line_width + grapheme_width > width {
+                ranges.push(start..end);
+                (start, line_width) = (index + grapheme_index, 0);
+            }
+            end = index + grapheme_index + grapheme.len();
+            line_width += grapheme_width;
+        }
+    }
+    if start < end || ranges.is_empty() {
+        ranges.push(start..end);
+    }
+    ranges
+}
+
+/// Cuts `line` down to `width` columns, including the ellipsis.
+fn truncate(line: &str, width: usize, truncation: Truncation) -> String {
+    let Some(available) = width.checked_sub(1) else {
+        return String::new();
+    };
+    let graphemes = line.graphemes(true).collect::<Vec<_>>();
+    let head = |width| take_width(graphemes.iter().copied(), width).concat();
+    let tail = |width| {
+        let mut tail = take_width(graphemes.iter().rev().copied(), width);
+        tail.reverse();
+        tail.concat()
+    };
+    match truncation {
+        Truncation::Start => format!("…{}", tail(available)),
+        Truncation::Middle => format!("{}…{}", head(available.div_ceil(2)), tail(available / 2)),
+        Truncation::End => format!("{}…", head(available)),
+    }
+}
+
+/// Takes graphemes until the next one would not fit in `width` columns.
+fn take_width<'a>(graphemes: impl Iterator<Item = &'a str>, width: usize) -> Vec<&'a str> {
+    let mut total = 0;
+    graphemes
+        .take_while(|grapheme| {
+            total += grapheme.width();
+            total <= width
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::buffer::*;
+    use crate::tests::render_with_state;
     use crate::view::Context;
 
     #[test]
@@ -156,4 +334,84 @@ mod tests {
         let size = char.size(Size::max());
         assert_eq!(size, Size::new(1, 1));
     }
+
+    fn render_plain(view: impl View, width: u16, height: u16) -> String {
+        render_with_state(&view, &mut AppState::new(), width, height).as_plain_str()
+    }
+
+    #[test]
+    fn test_text_word_wrap() {
+        let text = text("The quick brown fox");
+        assert_eq!(text.size(Size::new(10, 1)), Size::new(9, 2));
+
+        let expected = ["The quick ", "brown fox "].join("\n");
+        assert_eq!(render_plain(text, 10, 2), expected);
+    }
+
+    #[test]
+    fn test_text_word_wrap_splits_long_words() {
+        let text = text("a abcdefgh");
+        let expected = ["a    ", "abcde", "fgh  "].join("\n");
+        assert_eq!(render_plain(text, 5, 3), expected);
+    }
+
+    #[test]
+    fn test_text_character_wrap() {
+        let text = text("The quick brown").wrap(Wrap::Character);
+        let expected = ["The qu", "ick br", "own   "].join("\n");
+        assert_eq!(render_plain(text, 6, 3), expected);
+    }
+
+    #[test]
+    fn test_text_without_wrap() {
+        let text = text("The quick\nbrown fox").wrap(Wrap::None);
+        assert_eq!(text.size(Size::new(5, 10)), Size::new(5, 2));
+
+        let expected = ["The q", "brown"].join("\n");
+        assert_eq!(render_plain(text, 5, 2), expected);
+    }
+
+    #[test]
+    fn test_text_newlines() {
+        let text = text("one\n\nthree");
+        assert_eq!(text.size(Size::MAX), Size::new(5, 3));
+        assert_eq!("a\nbc".size(Size::MAX), Size::new(2, 2));
+    }
+
+    #[test]
+    fn test_text_truncation() {
+        let render = |truncation| render_plain(text("Hello, World!").truncate(truncation), 7, 1);
+        assert_eq!(render(Truncation::Start), "…World!");
+        assert_eq!(render(Truncation::Middle), "Hel…ld!");
+        assert_eq!(render(Truncation::End), "Hello,…");
+        assert_eq!(
+            text("Hi").truncate(Truncation::End).size(Size::MAX),
+            Size::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn test_vstack_allocates_wrapped_rows() {
+        let view = vstack((text("aaa bbb ccc"), text("---")));
+        assert_eq!(view.size(Size::new(4, 10)), Size::new(3, 4));
+
+        let expected = ["aaa ", "bbb ", "ccc ", "--- "].join("\n");
+        assert_eq!(render_plain(view, 4, 4), expected);
+    }
+
+    #[test]
+    fn test_wide_characters_keep_alignment() {
+        let view = hstack((text("日本"), "🦀", text("!")));
+        assert_eq!(view.size(Size::MAX), Size::new(9, 1));
+        assert_eq!(render_plain(view, 10, 1), "日本 🦀 ! ");
+    }
+
+    #[test]
+    fn test_wide_characters_truncate_and_wrap_by_width() {
+        let view = text("日本語").truncate(Truncation::End);
+        assert_eq!(render_plain(view, 4, 1), "日… ");
+
+        let view = text("日本語").wrap(Wrap::Character);
+        assert_eq!(render_plain(view, 5, 2), "日本 \n語   ");
+    }
 }
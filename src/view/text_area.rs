@@ -0,0 +1,691 @@
+use std::sync::Arc;
+
+use crossterm::event::KeyModifiers;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::*;
+
+/// The number of undo steps kept by a [`TextAreaState`].
+const MAX_HISTORY: usize = 100;
+
+/// A position in a [`TextAreaState`], as a line index and a grapheme column.
+type Position = (usize, usize);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: Position,
+}
+
+/// The editing state of a [`TextArea`], stored in the [`AppState`] keyed by its [`ViewId`].
+///
+/// Columns are counted in graphemes. Every edit is recorded in an undo history,
+/// with consecutive typing grouped into a single step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextAreaState {
+    lines: Vec<String>,
+    line: usize,
+    column: usize,
+    /// The column Up and Down try to return to when passing through shorter lines.
+    preferred_column: Option<usize>,
+    tab_width: usize,
+    /// The first visible line.
+    scroll_y: usize,
+    /// The first visible display column.
+    scroll_x: usize,
+    /// The number of visible lines, used by PageUp and PageDown.
+    page_height: usize,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// Whether the next typed character joins the last undo step.
+    coalesce: bool,
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl TextAreaState {
+    pub fn new(text: impl Into<String>) -> Self {
+        let lines = text
+            .into()
+            .split('\n')
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let line = lines.len() - 1;
+        let column = lines[line].graphemes(true).count();
+        Self {
+            lines,
+            line,
+            column,
+            preferred_column: None,
+            tab_width: 4,
+            scroll_y: 0,
+            scroll_x: 0,
+            page_height: 1,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            coalesce: false,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the line and grapheme column of the cursor.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    /// Replaces the text, moving the cursor to the end and clearing the undo history.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        *self = Self {
+            tab_width: self.tab_width,
+            scroll_y: self.scroll_y,
+            scroll_x: self.scroll_x,
+            page_height: self.page_height,
+            ..Self::new(text)
+        };
+    }
+
+    /// Reverts the last edit. Returns false if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.redo.push(current);
+        true
+    }
+
+    /// Reapplies the last undone edit. Returns false if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.undo.push(current);
+        true
+    }
+
+    pub(crate) fn handle_key(&mut self, key: &KeyEvent) -> Edit {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let coalesce = std::mem::take(&mut self.coalesce);
+        let page = self.page_height as isize;
+
+        match key.code {
+            KeyCode::Char(c) if !ctrl && !alt => self.type_char(c, coalesce),
+            KeyCode::Enter => self.insert("\n"),
+            KeyCode::Tab => self.insert_tab(),
+            KeyCode::Backspace if ctrl || alt => self.delete_to(self.previous_word()),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete_to(self.next_position()),
+            KeyCode::Left if ctrl || alt => self.move_to(self.previous_word()),
+            KeyCode::Left => self.move_to(self.previous_position()),
+            KeyCode::Right if ctrl || alt => self.move_to(self.next_word()),
+            KeyCode::Right => self.move_to(self.next_position()),
+            KeyCode::Up => self.move_vertically(-1),
+            KeyCode::Down => self.move_vertically(1),
+            KeyCode::PageUp => self.move_vertically(-page),
+            KeyCode::PageDown => self.move_vertically(page),
+            KeyCode::Home if ctrl => self.move_to((0, 0)),
+            KeyCode::End if ctrl => self.move_to(self.end()),
+            KeyCode::Home => self.move_to((self.line, 0)),
+            KeyCode::End => self.move_to(self.line_end()),
+            KeyCode::Char(c) if ctrl => match c {
+                'a' => self.move_to((self.line, 0)),
+                'e' => self.move_to(self.line_end()),
+                'b' => self.move_to(self.previous_position()),
+                'f' => self.move_to(self.next_position()),
+                'p' => self.move_vertically(-1),
+                'n' => self.move_vertically(1),
+                'h' => self.backspace(),
+                'd' => self.delete_to(self.next_position()),
+                'w' => self.delete_to(self.previous_word()),
+                'u' => self.delete_to((self.line, 0)),
+                'k' if self.cursor() == self.line_end() => self.delete_to(self.next_position()),
+                'k' => self.delete_to(self.line_end()),
+                'z' if self.undo() => Edit::Changed,
+                'y' if self.redo() => Edit::Changed,
+                'z' | 'y' => Edit::Moved,
+                _ => Edit::Ignored,
+            },
+            KeyCode::Char(c) if alt => match c {
+                'b' => self.move_to(self.previous_word()),
+                'f' => self.move_to(self.next_word()),
+                'd' => self.delete_to(self.next_word()),
+                _ => Edit::Ignored,
+            },
+            _ => Edit::Ignored,
+        }
+    }
+
+    fn is_text(&self, text: &str) -> bool {
+        text.split('\n').eq(self.lines.iter().map(String::as_str))
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.lines[line].graphemes(true).count()
+    }
+
+    fn line_end(&self) -> Position {
+        (self.line, self.line_len(self.line))
+    }
+
+    fn end(&self) -> Position {
+        let line = self.lines.len() - 1;
+        (line, self.line_len(line))
+    }
+
+    fn previous_position(&self) -> Position {
+        match (self.line, self.column) {
+            (0, 0) => (0, 0),
+            (line, 0) => (line - 1, self.line_len(line - 1)),
+            (line, column) => (line, column - 1),
+        }
+    }
+
+    fn next_position(&self) -> Position {
+        if self.column < self.line_len(self.line) {
+            (self.line, self.column + 1)
+        } else if self.line + 1 < self.lines.len() {
+            (self.line + 1, 0)
+        } else {
+            self.cursor()
+        }
+    }
+
+    fn previous_word(&self) -> Position {
+        if self.column == 0 {
+            return self.previous_position();
+        }
+        let graphemes = self.lines[self.line].graphemes(true).collect::<Vec<_>>();
+        (self.line, previous_word_boundary(&graphemes, self.column))
+    }
+
+    fn next_word(&self) -> Position {
+        if self.column == self.line_len(self.line) {
+            return self.next_position();
+        }
+        let graphemes = self.lines[self.line].graphemes(true).collect::<Vec<_>>();
+        (self.line, next_word_boundary(&graphemes, self.column))
+    }
+
+    fn place(&mut self, (line, column): Position) {
+        self.line = line;
+        self.column = column;
+        self.preferred_column = None;
+    }
+
+    fn move_to(&mut self, position: Position) -> Edit {
+        self.place(position);
+        Edit::Moved
+    }
+
+    fn move_vertically(&mut self, delta: isize) -> Edit {
+        let column = *self.preferred_column.get_or_insert(self.column);
+        self.line = self
+            .line
+            .saturating_add_signed(delta)
+            .min(self.lines.len() - 1);
+        self.column = column.min(self.line_len(self.line));
+        Edit::Moved
+    }
+
+    /// Saves the current text as an undo step and forgets anything that was undone.
+    fn record(&mut self) {
+        if self.undo.len() == MAX_HISTORY {
+            self.undo.remove(0);
+        }
+        self.undo.push(Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor(),
+        });
+        self.redo.clear();
+    }
+
+    /// Replaces the text with a snapshot, returning a snapshot of the text it replaced.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let current = Snapshot {
+            lines: std::mem::replace(&mut self.lines, snapshot.lines),
+            cursor: self.cursor(),
+        };
+        self.place(snapshot.cursor);
+        self.coalesce = false;
+        current
+    }
+
+    /// Types a character, grouping runs of non-whitespace into one undo step.
+    fn type_char(&mut self, c: char, coalesce: bool) -> Edit {
+        if !coalesce {
+            self.record();
+        }
+        self.insert_unrecorded(&c.to_string());
+        self.coalesce = !c.is_whitespace();
+        Edit::Changed
+    }
+
+    fn insert(&mut self, s: &str) -> Edit {
+        self.record();
+        self.insert_unrecorded(s);
+        Edit::Changed
+    }
+
+    fn insert_unrecorded(&mut self, s: &str) {
+        let offset = byte_offset(&self.lines[self.line], self.column);
+        let tail = self.lines[self.line].split_off(offset);
+        let mut parts = s.split('\n');
+        self.lines[self.line].push_str(parts.next().unwrap_or_default());
+        let mut line = self.line;
+        for part in parts {
+            line += 1;
+            self.lines.insert(line, part.to_string());
+        }
+        let column = self.line_len(line);
+        self.lines[line].push_str(&tail);
+        self.place((line, column));
+    }
+
+    /// Inserts pasted text as one undo step, keeping its line breaks.
+    pub(crate) fn paste(&mut self, text: &str) -> Edit {
+        self.insert(&text.replace("\r\n", "\n").replace('\r', "\n"))
+    }
+
+    /// Inserts spaces up to the next tab stop.
+    fn insert_tab(&mut self) -> Edit {
+        let offset = byte_offset(&self.lines[self.line], self.column);
+        let width = self.lines[self.line][..offset].width();
+        let spaces = self.tab_width - width % self.tab_width;
+        self.insert(&" ".repeat(spaces))
+    }
+
+    /// Deletes one grapheme, or back to the previous tab stop within indentation.
+    fn backspace(&mut self) -> Edit {
+        let offset = byte_offset(&self.lines[self.line], self.column);
+        let in_indent = self.lines[self.line][..offset].chars().all(|c| c == ' ');
+        if self.column > 0 && in_indent {
+            let stop = (self.column - 1) / self.tab_width * self.tab_width;
+            self.delete_to((self.line, stop))
+        } else {
+            self.delete_to(self.previous_position())
+        }
+    }
+
+    /// Deletes everything between the cursor and `position`.
+    fn delete_to(&mut self, position: Position) -> Edit {
+        if position == self.cursor() {
+            return Edit::Moved;
+        }
+        self.record();
+        let (start, end) = (position.min(self.cursor()), position.max(self.cursor()));
+        let end_offset = byte_offset(&self.lines[end.0], end.1);
+        let tail = self.lines[end.0][end_offset..].to_string();
+        let start_offset = byte_offset(&self.lines[start.0], start.1);
+        self.lines[start.0].truncate(start_offset);
+        self.lines[start.0].push_str(&tail);
+        self.lines.drain(start.0 + 1..=end.0);
+        self.place(start);
+        Edit::Changed
+    }
+
+    /// Scrolls so the cursor fits in a `width` by `height` viewport.
+    fn scroll_to_cursor(&mut self, width: usize, height: usize) {
+        let (width, height) = (width.max(1), height.max(1));
+        self.page_height = height;
+
+        self.scroll_y = self.scroll_y.min(self.line);
+        if self.line >= self.scroll_y + height {
+            self.scroll_y = self.line + 1 - height;
+        }
+
+        let offset = byte_offset(&self.lines[self.line], self.column);
+        let x = self.lines[self.line][..offset].width();
+        self.scroll_x = self.scroll_x.min(x);
+        if x >= self.scroll_x + width {
+            self.scroll_x = x + 1 - width;
+        }
+    }
+}
+
+/// Returns the byte offset of the grapheme at `index` in `line`.
+fn byte_offset(line: &str, index: usize) -> usize {
+    line.grapheme_indices(true)
+        .nth(index)
+        .map_or(line.len(), |(offset, _)| offset)
+}
+
+/// An editable block of multi-line text.
+///
+/// Like a [`TextField`], the app owns the value and receives every edit through the
+/// change callback, while the cursor, scroll position and undo history live in a
+/// [`TextAreaState`]. Lines that do not fit scroll horizontally with the cursor.
+///
+/// Tab inserts spaces up to the next tab stop, and Backspace inside indentation
+/// removes back to the previous one, so use Shift-Tab to move focus out of a text area.
+/// Ctrl-Z and Ctrl-Y undo and redo. The other bindings match [`TextField`], with
+/// Up/Down, Ctrl-P/N and PageUp/PageDown moving between lines.
+pub struct TextArea<M> {
+    pub(crate) value: String,
+    pub(crate) placeholder: Option<String>,
+    pub(crate) tab_width: usize,
+    pub(crate) on_change: Arc<dyn Fn(String) -> M + Send + Sync>,
+}
+
+impl<M> Clone for TextArea<M> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            placeholder: self.placeholder.clone(),
+            tab_width: self.tab_width,
+            on_change: self.on_change.clone(),
+        }
+    }
+}
+
+impl<M> private::Sealed for TextArea<M> {}
+
+impl<M: Send + 'static> TextArea<M> {
+    pub fn new<F>(value: impl Into<String>, on_change: F) -> Self
+    where
+        F: Fn(String) -> M + Send + Sync + 'static,
+    {
+        Self {
+            value: value.into(),
+            placeholder: None,
+            tab_width: 4,
+            on_change: Arc::new(on_change),
+        }
+    }
+
+    /// Shows dimmed text while the text area is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the number of columns between tab stops. Defaults to 4.
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    fn register_key_handler(&self, id: &ViewId, state: &mut AppState) {
+        let area_id = id.clone();
+        let on_change = self.on_change.clone();
+        let handler = move |key: &KeyEvent, state: &mut AppState| {
+            let area = state.get_mut(&area_id, TextAreaState::default);
+            match area.handle_key(key) {
+                Edit::Ignored | Edit::Submitted => KeyResponse::Ignored,
+                Edit::Moved => KeyResponse::Consumed,
+                Edit::Changed => KeyResponse::Message(on_change(area.text())),
+            }
+        };
+        let handler: MessageHandler<M> = Arc::new(handler);
+        state.register_key_handler(id, KeyHandler::Message(Arc::new(handler)));
+
+        let area_id = id.clone();
+        let on_change = self.on_change.clone();
+        let paste_handler: PasteHandler<M> = Arc::new(move |text, state| {
+            let area = state.get_mut(&area_id, TextAreaState::default);
+            area.paste(text);
+            KeyResponse::Message(on_change(area.text()))
+        });
+        state.register_paste_handler(id, paste_handler);
+    }
+}
+
+impl<M: Send + 'static> View for TextArea<M> {
+    fn size(&self, proposed: Size) -> Size {
+        let placeholder_width = self.placeholder.as_deref().map_or(0, str::width);
+        let lines = self.value.split('\n');
+        let width = lines.clone().map(|line| line.width() + 1).max();
+        let width = width.unwrap_or(1).max(placeholder_width);
+        let height = lines.count();
+        Size::new(
+            (width as u16).min(proposed.width),
+            (height.min(u16::MAX as usize) as u16).min(proposed.height),
+        )
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        state.register_focusable(id);
+        self.register_key_handler(id, state);
+        let is_focused = state.is_focused(id);
+
+        let rect = context.rect;
+        let area = state.get_mut(id, || TextAreaState::new(&self.value[..]));
+        area.tab_width = self.tab_width;
+        if !area.is_text(&self.value) {
+            area.set_text(&self.value[..]);
+        }
+        let (width, height) = (rect.size.width as usize, rect.size.height as usize);
+        area.scroll_to_cursor(width, height);
+
+        if self.value.is_empty() {
+            if let Some(ref placeholder) = self.placeholder {
+                buffer.set_clipped_string_at(
+                    context.clip,
+                    rect.left(),
+                    rect.top(),
+                    rect.size.width,
+                    placeholder,
+                    context.fg,
+                    None,
+                    context.modifier | Modifier::DIM,
+                );
+            }
+        }
+
+        // The cursor after a line sits on a space, or on the placeholder while empty.
+        let end = match self.placeholder.as_deref() {
+            Some(placeholder) if self.value.is_empty() => placeholder.graphemes(true).next(),
+            _ => None,
+        };
+        let visible_lines = area.lines.iter().enumerate().skip(area.scroll_y);
+        for (line_index, line) in visible_lines.take(height) {
+            let y = rect.top() + (line_index - area.scroll_y) as u16;
+            let len = line.graphemes(true).count();
+            let mut x = 0;
+            for (column, grapheme) in line.graphemes(true).chain([end.unwrap_or(" ")]).enumerate() {
+                let grapheme_width = grapheme.width();
+                let start = x;
+                x += grapheme_width;
+                if start < area.scroll_x {
+                    continue;
+                }
+                let left = start - area.scroll_x;
+                if left + grapheme_width > width {
+                    break;
+                }
+                let is_cursor = is_focused && (line_index, column) == area.cursor();
+                if column < len || is_cursor {
+                    let modifier = if is_cursor {
+                        context.modifier | Modifier::INVERSE
+                    } else {
+                        context.modifier
+                    };
+                    buffer.set_clipped_string_at(
+                        context.clip,
+                        rect.left() + left as u16,
+                        y,
+                        grapheme_width as u16,
+                        grapheme,
+                        context.fg,
+                        None,
+                        modifier,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::render_with_state;
+    use pretty_assertions::assert_eq;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn type_str(area: &mut TextAreaState, s: &str) {
+        for c in s.chars() {
+            let code = match c {
+                '\n' => KeyCode::Enter,
+                '\t' => KeyCode::Tab,
+                c => KeyCode::Char(c),
+            };
+            area.handle_key(&key(code, KeyModifiers::NONE));
+        }
+    }
+
+    #[test]
+    fn test_typing_across_lines() {
+        let mut area = TextAreaState::default();
+        type_str(&mut area, "first\nsecond");
+        assert_eq!(area.text(), "first\nsecond");
+        assert_eq!(area.cursor(), (1, 6));
+
+        area.handle_key(&key(KeyCode::Home, KeyModifiers::NONE));
+        area.handle_key(&key(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(area.text(), "firstsecond");
+        assert_eq!(area.cursor(), (0, 5));
+    }
+
+    #[test]
+    fn test_vertical_movement_keeps_column() {
+        let mut area = TextAreaState::new("a long line\nab\nanother line");
+        area.handle_key(&key(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(area.cursor(), (1, 2));
+        area.handle_key(&key(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(area.cursor(), (0, 11));
+
+        area.handle_key(&key(KeyCode::Left, KeyModifiers::CONTROL));
+        area.handle_key(&key(KeyCode::Down, KeyModifiers::NONE));
+        area.handle_key(&key(KeyCode::Down, KeyModifiers::NONE));
+        assert_eq!(area.cursor(), (2, 7));
+    }
+
+    #[test]
+    fn test_word_and_line_deletion() {
+        let mut area = TextAreaState::new("one two\nthree");
+        area.handle_key(&key(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert_eq!(area.text(), "one two\n");
+
+        area.handle_key(&key(KeyCode::Up, KeyModifiers::NONE));
+        area.handle_key(&key(KeyCode::Char('a'), KeyModifiers::CONTROL));
+        area.handle_key(&key(KeyCode::Char('k'), KeyModifiers::CONTROL));
+        assert_eq!(area.text(), "\n");
+        area.handle_key(&key(KeyCode::Char('k'), KeyModifiers::CONTROL));
+        assert_eq!(area.text(), "");
+    }
+
+    #[test]
+    fn test_undo_groups_typing() {
+        let mut area = TextAreaState::default();
+        type_str(&mut area, "hello world");
+        area.handle_key(&key(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(area.text(), "hello worl");
+
+        assert!(area.undo());
+        assert_eq!(area.text(), "hello world");
+        assert!(area.undo());
+        assert_eq!(area.text(), "hello ");
+        assert!(area.undo());
+        assert_eq!(area.text(), "");
+        assert!(!area.undo());
+
+        area.handle_key(&key(KeyCode::Char('y'), KeyModifiers::CONTROL));
+        assert_eq!(area.text(), "hello ");
+        assert_eq!(area.cursor(), (0, 6));
+
+        type_str(&mut area, "!");
+        assert!(!area.redo());
+    }
+
+    #[test]
+    fn test_paste_is_one_undo_step() {
+        let mut area = TextAreaState::new("ab");
+        area.handle_key(&key(KeyCode::Left, KeyModifiers::NONE));
+        area.paste("1\r\n2\r3");
+        assert_eq!(area.text(), "a1\n2\n3b");
+        assert_eq!(area.cursor(), (2, 1));
+
+        assert!(area.undo());
+        assert_eq!(area.text(), "ab");
+    }
+
+    #[test]
+    fn test_soft_tabs() {
+        let mut area = TextAreaState::default();
+        type_str(&mut area, "ab\t\n\t\tx");
+        assert_eq!(area.text(), "ab  \n        x");
+
+        area.handle_key(&key(KeyCode::Left, KeyModifiers::NONE));
+        area.handle_key(&key(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(area.text(), "ab  \n    x");
+        area.handle_key(&key(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(area.text(), "ab  \nx");
+    }
+
+    #[test]
+    fn test_text_area_scrolls_to_cursor() {
+        let view = text_area("one\ntwo\nthree long", |s| s);
+        let mut state = AppState::new();
+        let expected = ["    ", "    ", "ong "].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 4, 3).as_plain_str(),
+            expected
+        );
+
+        state.dispatch_key::<String>(&key(KeyCode::Home, KeyModifiers::CONTROL));
+        let expected = ["one ", "two ", "thre"].join("\n");
+        assert_eq!(
+            render_with_state(&view, &mut state, 4, 3).as_plain_str(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_text_area_produces_change_messages() {
+        let view = text_area("hi", |s| s).placeholder("Notes");
+        let mut state = AppState::new();
+        render_with_state(&view, &mut state, 5, 2);
+
+        let response = state.dispatch_key::<String>(&key(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(response, KeyResponse::Message("hi\n".to_string()));
+
+        let response = state.dispatch_key::<String>(&key(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(response, KeyResponse::Consumed);
+
+        let response = state.dispatch_key::<String>(&key(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(response, KeyResponse::Ignored);
+    }
+
+    #[test]
+    fn test_text_area_renders_placeholder() {
+        let view = text_area("", |s| s).placeholder("Notes");
+        let expected = vec!["Notes "];
+        crate::tests::assert_rendered_view(view, expected, 6, 1);
+    }
+
+    #[test]
+    fn test_focused_empty_area_shows_cursor_on_placeholder() {
+        let view = text_area("", |s| s).placeholder("Notes");
+        let mut buffer = render_with_state(&view, &mut AppState::new(), 6, 1);
+        assert_eq!(buffer.as_plain_str(), "Notes ");
+        assert!(buffer.get_mut(0, 0).modifier.contains(Modifier::INVERSE));
+        assert!(!buffer.get_mut(1, 0).modifier.contains(Modifier::INVERSE));
+    }
+}
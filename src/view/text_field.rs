@@ -0,0 +1,502 @@
+use std::sync::Arc;
+
+use crossterm::event::KeyModifiers;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::*;
+
+/// What a key event did to a [`TextFieldState`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Edit {
+    Ignored,
+    Moved,
+    Changed,
+    Submitted,
+}
+
+/// The editing state of a [`TextField`], stored in the [`AppState`] keyed by its [`ViewId`].
+///
+/// Positions are counted in graphemes, so the cursor never lands inside a
+/// multi-codepoint character.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextFieldState {
+    text: String,
+    /// The grapheme index the cursor is in front of.
+    cursor: usize,
+    /// The other end of the selection, if any. The cursor is the moving end.
+    anchor: Option<usize>,
+    /// The index of the first visible grapheme.
+    scroll: usize,
+}
+
+impl TextFieldState {
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let cursor = text.graphemes(true).count();
+        Self {
+            text,
+            cursor,
+            anchor: None,
+            scroll: 0,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the selected grapheme range, if anything is selected.
+    pub fn selection(&self) -> Option<std::ops::Range<usize>> {
+        let anchor = self.anchor.filter(|anchor| *anchor != self.cursor)?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    /// Replaces the text, keeping the cursor at the end.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        *self = Self {
+            scroll: self.scroll,
+            ..Self::new(text)
+        };
+    }
+
+    pub(crate) fn handle_key(&mut self, key: &KeyEvent) -> Edit {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+
+        match key.code {
+            KeyCode::Char(c) if !ctrl && !alt => self.insert(&c.to_string()),
+            KeyCode::Enter => Edit::Submitted,
+            KeyCode::Backspace if ctrl || alt => self.delete_to(self.previous_word()),
+            KeyCode::Backspace => self.delete_to(self.cursor.saturating_sub(1)),
+            KeyCode::Delete => self.delete_to(self.cursor + 1),
+            KeyCode::Left if ctrl || alt => self.move_to(self.previous_word(), shift),
+            KeyCode::Left => self.move_to(self.cursor.saturating_sub(1), shift),
+            KeyCode::Right if ctrl || alt => self.move_to(self.next_word(), shift),
+            KeyCode::Right => self.move_to(self.cursor + 1, shift),
+            KeyCode::Home => self.move_to(0, shift),
+            KeyCode::End => self.move_to(self.len(), shift),
+            KeyCode::Char(c) if ctrl => match c {
+                'a' => self.move_to(0, false),
+                'e' => self.move_to(self.len(), false),
+                'b' => self.move_to(self.cursor.saturating_sub(1), false),
+                'f' => self.move_to(self.cursor + 1, false),
+                'h' => self.delete_to(self.cursor.saturating_sub(1)),
+                'd' => self.delete_to(self.cursor + 1),
+                'w' => self.delete_to(self.previous_word()),
+                'u' => self.delete_to(0),
+                'k' => self.delete_to(self.len()),
+                _ => Edit::Ignored,
+            },
+            KeyCode::Char(c) if alt => match c {
+                'b' => self.move_to(self.previous_word(), false),
+                'f' => self.move_to(self.next_word(), false),
+                'd' => self.delete_to(self.next_word()),
+                _ => Edit::Ignored,
+            },
+            _ => Edit::Ignored,
+        }
+    }
+
+    /// Inserts pasted text in one edit. Line breaks become spaces, so a paste never submits.
+    pub(crate) fn paste(&mut self, text: &str) -> Edit {
+        let text = text.replace("\r\n", " ").replace(['\r', '\n'], " ");
+        self.insert(&text)
+    }
+
+    fn len(&self) -> usize {
+        self.text.graphemes(true).count()
+    }
+
+    /// Returns the byte offset of the grapheme at `index`.
+    fn byte_offset(&self, index: usize) -> usize {
+        self.text
+            .grapheme_indices(true)
+            .nth(index)
+            .map_or(self.text.len(), |(offset, _)| offset)
+    }
+
+    fn move_to(&mut self, index: usize, select: bool) -> Edit {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = index.min(self.len());
+        Edit::Moved
+    }
+
+    /// Replaces the selection, or inserts at the cursor.
+    fn insert(&mut self, s: &str) -> Edit {
+        self.delete_selection();
+        let offset = self.byte_offset(self.cursor);
+        self.text.insert_str(offset, s);
+        self.cursor += s.graphemes(true).count();
+        Edit::Changed
+    }
+
+    /// Deletes the selection, or everything between the cursor and `index`.
+    fn delete_to(&mut self, index: usize) -> Edit {
+        if self.delete_selection() {
+            return Edit::Changed;
+        }
+        let index = index.min(self.len());
+        if index == self.cursor {
+            return Edit::Moved;
+        }
+        let (start, end) = (self.cursor.min(index), self.cursor.max(index));
+        let range = self.byte_offset(start)..self.byte_offset(end);
+        self.text.replace_range(range, "");
+        self.cursor = start;
+        Edit::Changed
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let Some(selection) = self.selection() else {
+            self.anchor = None;
+            return false;
+        };
+        let range = self.byte_offset(selection.start)..self.byte_offset(selection.end);
+        self.text.replace_range(range, "");
+        self.cursor = selection.start;
+        self.anchor = None;
+        true
+    }
+
+    fn previous_word(&self) -> usize {
+        previous_word_boundary(&self.text.graphemes(true).collect::<Vec<_>>(), self.cursor)
+    }
+
+    fn next_word(&self) -> usize {
+        next_word_boundary(&self.text.graphemes(true).collect::<Vec<_>>(), self.cursor)
+    }
+
+    /// Scrolls horizontally so the cursor fits in `width` columns.
+    fn scroll_to_cursor(&mut self, width: u16) {
+        let graphemes = self.text.graphemes(true).collect::<Vec<_>>();
+        self.scroll = self.scroll.min(self.cursor);
+        let width = width.max(1) as usize;
+        while graphemes[self.scroll..self.cursor]
+            .iter()
+            .map(|g| g.width())
+            .sum::<usize>()
+            >= width
+        {
+            self.scroll += 1;
+        }
+    }
+}
+
+fn is_word(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Returns the start of the word before `index`, skipping any whitespace or punctuation.
+pub(crate) fn previous_word_boundary(graphemes: &[&str], index: usize) -> usize {
+    let mut index = index.min(graphemes.len());
+    while index > 0 && !is_word(graphemes[index - 1]) {
+        index -= 1;
+    }
+    while index > 0 && is_word(graphemes[index - 1]) {
+        index -= 1;
+    }
+    index
+}
+
+/// Returns the end of the word after `index`, skipping any whitespace or punctuation.
+pub(crate) fn next_word_boundary(graphemes: &[&str], index: usize) -> usize {
+    let mut index = index.min(graphemes.len());
+    while index < graphemes.len() && !is_word(graphemes[index]) {
+        index += 1;
+    }
+    while index < graphemes.len() && is_word(graphemes[index]) {
+        index += 1;
+    }
+    index
+}
+
+type TextCallback<M> = Arc<dyn Fn(String) -> M + Send + Sync>;
+
+/// An editable single line of text.
+///
+/// The app owns the value and receives every edit through the change callback.
+/// The cursor, selection and scroll position live in a [`TextFieldState`].
+/// A text field is focusable, and only shows its cursor while focused.
+///
+/// Key bindings follow readline: Ctrl-A/E, Ctrl-B/F, Alt-B/F, Ctrl-W, Ctrl-U, Ctrl-K,
+/// Alt-D, Ctrl-H, Ctrl-D, along with the arrow keys, Home/End and Shift to select.
+pub struct TextField<M> {
+    pub(crate) value: String,
+    pub(crate) placeholder: Option<String>,
+    pub(crate) on_change: TextCallback<M>,
+    pub(crate) on_submit: Option<TextCallback<M>>,
+}
+
+impl<M> Clone for TextField<M> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            placeholder: self.placeholder.clone(),
+            on_change: self.on_change.clone(),
+            on_submit: self.on_submit.clone(),
+        }
+    }
+}
+
+impl<M> private::Sealed for TextField<M> {}
+
+impl<M: Send + 'static> TextField<M> {
+    pub fn new<F>(value: impl Into<String>, on_change: F) -> Self
+    where
+        F: Fn(String) -> M + Send + Sync + 'static,
+    {
+        Self {
+            value: value.into(),
+            placeholder: None,
+            on_change: Arc::new(on_change),
+            on_submit: None,
+        }
+    }
+
+    /// Shows dimmed text while the field is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Produces a message when Enter is pressed. Without it, Enter is passed on to the app.
+    pub fn on_submit<F>(mut self, on_submit: F) -> Self
+    where
+        F: Fn(String) -> M + Send + Sync + 'static,
+    {
+        self.on_submit = Some(Arc::new(on_submit));
+        self
+    }
+
+    fn register_key_handler(&self, id: &ViewId, state: &mut AppState) {
+        let field_id = id.clone();
+        let on_change = self.on_change.clone();
+        let on_submit = self.on_submit.clone();
+        let handler = move |key: &KeyEvent, state: &mut AppState| {
+            let field = state.get_mut(&field_id, TextFieldState::default);
+            match field.handle_key(key) {
+                Edit::Ignored => KeyResponse::Ignored,
+                Edit::Moved => KeyResponse::Consumed,
+                Edit::Changed => KeyResponse::Message(on_change(field.text.clone())),
+                Edit::Submitted => match on_submit {
+                    Some(ref on_submit) => KeyResponse::Message(on_submit(field.text.clone())),
+                    None => KeyResponse::Ignored,
+                },
+            }
+        };
+        let handler: MessageHandler<M> = Arc::new(handler);
+        state.register_key_handler(id, KeyHandler::Message(Arc::new(handler)));
+
+        let field_id = id.clone();
+        let on_change = self.on_change.clone();
+        let paste_handler: PasteHandler<M> = Arc::new(move |text, state| {
+            let field = state.get_mut(&field_id, TextFieldState::default);
+            field.paste(text);
+            KeyResponse::Message(on_change(field.text.clone()))
+        });
+        state.register_paste_handler(id, paste_handler);
+    }
+}
+
+impl<M: Send + 'static> View for TextField<M> {
+    fn size(&self, proposed: Size) -> Size {
+        let placeholder_width = self.placeholder.as_deref().map_or(0, str::width);
+        let width = (self.value.width() + 1).max(placeholder_width);
+        Size::new((width as u16).min(proposed.width), 1.min(proposed.height))
+    }
+
+    fn render(&self, id: &mut ViewId, context: Context, state: &mut AppState, buffer: &mut Buffer) {
+        state.register_focusable(id);
+        self.register_key_handler(id, state);
+        let is_focused = state.is_focused(id);
+
+        let rect = context.rect;
+        let field = state.get_mut(id, || TextFieldState::new(&self.value[..]));
+        if field.text != self.value {
+            field.set_text(&self.value[..]);
+        }
+        field.scroll_to_cursor(rect.size.width);
+
+        if field.text.is_empty() {
+            if let Some(ref placeholder) = self.placeholder {
+                buffer.set_clipped_string_at(
+                    context.clip,
+                    rect.left(),
+                    rect.top(),
+                    rect.size.width,
+                    placeholder,
+                    context.fg,
+                    None,
+                    context.modifier | Modifier::DIM,
+                );
+            }
+        }
+
+        let selection = field.selection().unwrap_or_default();
+        let mut x = rect.left();
+        // The cursor after the text sits on a space, or on the placeholder while empty.
+        let end = match self.placeholder.as_deref() {
+            Some(placeholder) if field.text.is_empty() => placeholder.graphemes(true).next(),
+            _ => None,
+        };
+        let graphemes = field.text.graphemes(true).chain([end.unwrap_or(" ")]);
+        for (index, grapheme) in graphemes.enumerate().skip(field.scroll) {
+            let width = grapheme.width() as u16;
+            if x + width > rect.right() {
+                break;
+            }
+            let is_cursor = is_focused && index == field.cursor;
+            let modifier = if is_focused && selection.contains(&index) || is_cursor {
+                context.modifier | Modifier::INVERSE
+            } else {
+                context.modifier
+            };
+            if index < field.len() || is_cursor {
+                buffer.set_clipped_string_at(
+                    context.clip,
+                    x,
+                    rect.top(),
+                    width,
+                    grapheme,
+                    context.fg,
+                    None,
+                    modifier,
+                );
+            }
+            x += width;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn type_keys(field: &mut TextFieldState, keys: &[KeyEvent]) {
+        for key in keys {
+            field.handle_key(key);
+        }
+    }
+
+    #[test]
+    fn test_insert_and_move() {
+        let mut field = TextFieldState::new("hllo");
+        type_keys(
+            &mut field,
+            &[
+                key(KeyCode::Home, KeyModifiers::NONE),
+                key(KeyCode::Right, KeyModifiers::NONE),
+                key(KeyCode::Char('e'), KeyModifiers::NONE),
+            ],
+        );
+        assert_eq!(field.text(), "hello");
+        assert_eq!(field.cursor(), 2);
+    }
+
+    #[test]
+    fn test_readline_bindings() {
+        let mut field = TextFieldState::new("foo bar baz");
+        field.handle_key(&key(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert_eq!(field.text(), "foo bar ");
+
+        field.handle_key(&key(KeyCode::Char('a'), KeyModifiers::CONTROL));
+        field.handle_key(&key(KeyCode::Char('f'), KeyModifiers::ALT));
+        assert_eq!(field.cursor(), 3);
+
+        field.handle_key(&key(KeyCode::Char('k'), KeyModifiers::CONTROL));
+        assert_eq!(field.text(), "foo");
+
+        field.handle_key(&key(KeyCode::Char('b'), KeyModifiers::CONTROL));
+        field.handle_key(&key(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        assert_eq!(field.text(), "o");
+        assert_eq!(field.cursor(), 0);
+    }
+
+    #[test]
+    fn test_grapheme_aware_deletion() {
+        let mut field = TextFieldState::new("ae\u{301}");
+        assert_eq!(field.cursor(), 2);
+        field.handle_key(&key(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(field.text(), "a");
+    }
+
+    #[test]
+    fn test_selection_is_replaced() {
+        let mut field = TextFieldState::new("hello world");
+        type_keys(
+            &mut field,
+            &[
+                key(KeyCode::Left, KeyModifiers::SHIFT | KeyModifiers::CONTROL),
+                key(KeyCode::Char('W'), KeyModifiers::SHIFT),
+            ],
+        );
+        assert_eq!(field.text(), "hello W");
+        assert_eq!(field.selection(), None);
+    }
+
+    #[test]
+    fn test_text_field_renders_placeholder() {
+        let view = text_field("", |s| s).placeholder("Name");
+        let expected = vec!["Name  "];
+        crate::tests::assert_rendered_view(view, expected, 6, 1);
+    }
+
+    #[test]
+    fn test_focused_empty_field_shows_cursor_on_placeholder() {
+        let view = text_field("", |s| s).placeholder("Name");
+        let mut buffer = crate::tests::render_with_state(&view, &mut AppState::new(), 6, 1);
+        assert_eq!(buffer.as_plain_str(), "Name  ");
+        assert!(buffer.get_mut(0, 0).modifier.contains(Modifier::INVERSE));
+        assert!(!buffer.get_mut(1, 0).modifier.contains(Modifier::INVERSE));
+    }
+
+    #[test]
+    fn test_text_field_scrolls_to_cursor() {
+        let view = text_field("abcdefgh", |s| s);
+        let buffer = crate::tests::render_with_state(&view, &mut AppState::new(), 5, 1);
+        assert_eq!(buffer.as_plain_str(), "efgh ");
+    }
+
+    #[test]
+    fn test_text_field_produces_change_messages() {
+        let view = text_field("hi", |s| s);
+        let mut state = AppState::new();
+        crate::tests::render_with_state(&view, &mut state, 5, 1);
+
+        let response = state.dispatch_key::<String>(&key(KeyCode::Char('!'), KeyModifiers::NONE));
+        assert_eq!(response, KeyResponse::Message("hi!".to_string()));
+
+        let response = state.dispatch_key::<String>(&key(KeyCode::Left, KeyModifiers::NONE));
+        assert_eq!(response, KeyResponse::Consumed);
+
+        let response = state.dispatch_key::<String>(&key(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(response, KeyResponse::Ignored);
+    }
+
+    #[test]
+    fn test_paste_is_one_edit_on_one_line() {
+        let mut field = TextFieldState::new("ab");
+        field.handle_key(&key(KeyCode::Left, KeyModifiers::NONE));
+        assert_eq!(field.paste("1\r\n2\n3"), Edit::Changed);
+        assert_eq!(field.text(), "a1 2 3b");
+        assert_eq!(field.cursor(), 6);
+    }
+}
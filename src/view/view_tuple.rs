@@ -54,7 +54,7 @@ where
     V: View,
 {
     fn make_iterator(&self) -> impl Iterator<Item = IdentifiedView<&dyn View>> {
-        self.into_iter().map(|value| IdentifiedView {
+        self.iter().map(|value| IdentifiedView {
             id: value.id,
             value: &value.value as &dyn View,
         })